@@ -1,15 +1,52 @@
-use chrono::NaiveDate;
+#[path = "build/callout.rs"]
+mod callout;
+#[path = "build/code_block.rs"]
+mod code_block;
+#[path = "build/diagnostics.rs"]
+mod diagnostics;
+#[path = "build/diagram.rs"]
+mod diagram;
+#[path = "build/front_matter.rs"]
+mod front_matter;
+#[path = "build/images.rs"]
+mod images;
+#[path = "build/links.rs"]
+mod links;
+#[path = "build/math.rs"]
+mod math;
+#[path = "build/reading_time.rs"]
+mod reading_time;
+#[path = "build/related.rs"]
+mod related;
+#[path = "build/search_index.rs"]
+mod search_index;
+#[path = "build/syntax_theme.rs"]
+mod syntax_theme;
+#[path = "build/toc.rs"]
+mod toc;
+#[path = "src/shared/search/tokenize.rs"]
+mod tokenize;
+
+use callout::render_callout;
+use chrono::{Datelike, NaiveDate};
+use code_block::{FenceInfo, render_code_block};
+use diagnostics::Diagnostics;
+use diagram::{is_diagram, render_diagram};
+use front_matter::split_front_matter;
+use images::rewrite_images;
+use links::{check_links, rewrite_links};
+use math::render_math;
 use pulldown_cmark::{CodeBlockKind, CowStr, Event, Options, Parser, Tag, TagEnd, html};
-use std::{env, fs, path::Path};
-use syntect::{
-    easy::HighlightLines,
-    highlighting::ThemeSet,
-    html::{
-        IncludeBackground, append_highlighted_html_for_styled_line, start_highlighted_html_snippet,
-    },
-    parsing::SyntaxSet,
-    util::LinesWithEndings,
+use reading_time::{ReadingStats, WordCounter};
+use related::assign_related;
+use search_index::write_search_index;
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
 };
+use syntax_theme::write_syntax_css;
+use toc::{Heading, add_heading_ids, toc_literal};
 
 fn main() {
     // To rerun the build script if contents changes
@@ -18,127 +55,519 @@ fn main() {
     // Generate output file path
     let out_dir = env::var("OUT_DIR").unwrap();
 
+    // Content errors are collected across all files and reported together
+    let mut diagnostics = Diagnostics::default();
+
+    // Drafts are left out of release builds
+    let include_drafts = include_drafts();
+
     // Generate output file path for posts data
     let post_dest_path = Path::new(&out_dir).join("posts_data.rs");
-    let posts = list_from_md_files(Path::new("./contents/posts"));
-    write_out_list("POSTS", posts, &post_dest_path);
+    let mut posts = list_from_md_files(Path::new("./contents/posts"), &mut diagnostics);
+    posts.retain(|post| include_drafts || !post.draft);
+    assign_related(&mut posts);
+    write_out_list("POSTS", "ContentKind::Post", &posts, &post_dest_path);
 
     // Generate output file path for poems data
     let poem_dest_path = Path::new(&out_dir).join("poems_data.rs");
-    let poems = list_from_md_files(Path::new("./contents/poems"));
-    write_out_list("POEMS", poems, &poem_dest_path);
+    let mut poems = list_from_md_files(Path::new("./contents/poems"), &mut diagnostics);
+    poems.retain(|poem| include_drafts || !poem.draft);
+    assign_related(&mut poems);
+    write_out_list("POEMS", "ContentKind::Poem", &poems, &poem_dest_path);
+
+    // Fail on links to entries or headings that do not exist - the entries are already written,
+    // so in lenient mode they are published with the dead link
+    check_links(
+        &[("posts", posts.as_slice()), ("poems", poems.as_slice())],
+        &mut diagnostics,
+    );
+
+    // Generate output file path for the search index over posts and poems
+    let search_dest_path = Path::new(&out_dir).join("search_index.rs");
+    write_search_index(
+        &[
+            ("ContentKind::Post", posts.as_slice()),
+            ("ContentKind::Poem", poems.as_slice()),
+        ],
+        &search_dest_path,
+    );
+
+    // Generate the code block stylesheet for the light and dark themes
+    write_syntax_css(Path::new(&out_dir));
+
+    // Fail the build on content errors (or warn in lenient mode)
+    diagnostics.report();
 }
 
 // ===========================
 // BUILD HELPERS
 // ===========================
-fn write_out_list(varname: &str, posts: Vec<(u32, NaiveDate, String, String)>, dest_path: &Path) {
-    let entries: Vec<String> = posts
-        .iter()
-        .map(|(id, date, title, html)| {
-            format!(
-                "    (\"{}\", \"{}\", \"{}\", r###\"{}\"###),",
-                id,
-                date.format("%d-%b-%Y"), // DD-MMM-YYYY eg: 05-Sep-2025
-                title,
-                html
-            )
-        })
-        .collect();
+// Drafts are built in debug builds only - not from LEPTOS_ENV, which cargo-leptos sets to the
+// `env` of Cargo.toml (DEV) for release builds too. PROFILE is `release` for every profile
+// inheriting from it, eg: wasm-release
+fn include_drafts() -> bool {
+    env::var("PROFILE").is_ok_and(|profile| profile != "release")
+}
+
+// Search engines show about this many characters of a description
+const DESCRIPTION_CHARS: usize = 160;
+
+struct Content {
+    // Markdown file of the entry, for error reports
+    source: PathBuf,
+    id: u32,
+    date: NaiveDate,
+    title: String,
+    slug: String,
+    summary: Option<String>,
+    tags: Vec<String>,
+    author: Option<String>,
+    cover_image: Option<String>,
+    draft: bool,
+    // Unix timestamp the entry is hidden until
+    publish_at: Option<i64>,
+    html: String,
+    // Headings of the body, for the table of contents
+    headings: Vec<Heading>,
+    word_count: u32,
+    reading_minutes: u32,
+    // Meta description: summary or the start of the first paragraph
+    description: String,
+    // Plain text of the body, for the search index
+    text: String,
+    // Ids of related entries of the same section, most related first
+    related: Vec<u32>,
+    // Links within the site, checked once every entry is known
+    links: Vec<String>,
+}
+
+fn write_out_list(varname: &str, kind: &str, posts: &[Content], dest_path: &Path) {
+    let entries: Vec<String> = posts.iter().map(|post| entry_literal(kind, post)).collect();
 
     fs::write(
         dest_path,
         format!(
-            "static {varname}: &[(&str, &str, &str, &str)] = &[\n{}\n];",
+            "pub static {varname}: &[ContentEntry] = &[\n{}\n];",
             entries.join("\n")
         ),
     )
     .unwrap();
 }
 
+// Render a post as a `crate::shared::content::ContentEntry` literal
+fn entry_literal(kind: &str, post: &Content) -> String {
+    format!(
+        r####"    ContentEntry {{
+        kind: {},
+        id: {},
+        date: NaiveDate::from_ymd_opt({}, {}, {}).unwrap(),
+        title: {:?},
+        slug: {:?},
+        summary: {:?},
+        tags: &{:?},
+        author: {:?},
+        cover_image: {:?},
+        draft: {},
+        publish_at: {:?},
+        html: r###"{}"###,
+        toc: {},
+        word_count: {},
+        reading_minutes: {},
+        description: {:?},
+        related: &{:?},
+    }},"####,
+        kind,
+        post.id,
+        post.date.year(),
+        post.date.month(),
+        post.date.day(),
+        post.title,
+        post.slug,
+        post.summary,
+        post.tags,
+        post.author,
+        post.cover_image,
+        post.draft,
+        post.publish_at,
+        post.html,
+        toc_literal(&post.headings),
+        post.word_count,
+        post.reading_minutes,
+        post.description,
+        post.related,
+    )
+}
+
 // Read markdown files from the specified directory and return a list of posts
-// Purposely control types u32 and NaiveDate
-fn list_from_md_files(path: &Path) -> Vec<(u32, NaiveDate, String, String)> {
-    // Read all markdown files in the directory
-    let mut posts: Vec<_> = fs::read_dir(path)
+// Invalid files are reported to the diagnostics and left out of the list
+fn list_from_md_files(path: &Path, diagnostics: &mut Diagnostics) -> Vec<Content> {
+    // Only markdown files are content, anything else in the directory is ignored
+    let mut files: Vec<_> = fs::read_dir(path)
         .unwrap()
-        .filter_map(|entry| {
-            let file_path = entry.ok()?.path();
-            let file_name = file_path.file_name()?.to_str()?;
-            let parts: Vec<&str> = file_name.trim_end_matches(".md").splitn(3, '-').collect();
-            if parts.len() != 3 {
-                return None;
-            }
-            let id = parts[0].parse::<u32>().ok()?;
-            let date = NaiveDate::parse_from_str(parts[1], "%Y%m%d").ok()?;
-            let markdown = fs::read_to_string(&file_path).ok()?;
-            let title = markdown
-                .lines()
-                .next()
-                .and_then(|line| line.strip_prefix("# "))?
-                .to_string();
-            let html = markdown_to_html(&markdown);
-            Some((id, date, title, html))
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|file_path| {
+            file_path.is_file() && file_path.extension().is_some_and(|ext| ext == "md")
         })
         .collect();
+    files.sort();
+
+    let mut posts: Vec<Content> = Vec::new();
+    let mut seen_ids: HashMap<u32, String> = HashMap::new();
+    let mut seen_slugs: HashMap<String, String> = HashMap::new();
+    for file_path in files {
+        let Some(post) = parse_md_file(&file_path, diagnostics) else {
+            continue;
+        };
+        let file_name = file_path.file_name().unwrap().to_string_lossy().to_string();
+        if let Some(first) = seen_ids.get(&post.id) {
+            diagnostics.error(
+                &file_path,
+                None,
+                format!("duplicate id {} (already used by {first})", post.id),
+            );
+            continue;
+        }
+        if let Some(first) = seen_slugs.get(&post.slug) {
+            diagnostics.error(
+                &file_path,
+                None,
+                format!("duplicate slug `{}` (already used by {first})", post.slug),
+            );
+            continue;
+        }
+        seen_ids.insert(post.id, file_name.clone());
+        seen_slugs.insert(post.slug.clone(), file_name);
+        posts.push(post);
+    }
 
     // Sort posts by id - descending/newest first
-    posts.sort_by_key(|(id, _, _, _)| std::cmp::Reverse(*id));
+    posts.sort_by_key(|post| std::cmp::Reverse(post.id));
 
     posts
 }
 
-fn markdown_to_html(markdown: &str) -> String {
+// Parse a single content file
+// Filename pattern is `<id>-<yyyymmdd>-<slug>.md`; front matter values take precedence
+// Purposely control types u32 and NaiveDate
+fn parse_md_file(file_path: &Path, diagnostics: &mut Diagnostics) -> Option<Content> {
+    let errors_before = diagnostics.error_count();
+    let stem = file_path.file_stem().unwrap().to_string_lossy();
+
+    // Filename: id is mandatory, the date segment is optional when front matter has a date
+    let (raw_id, rest) = stem.split_once('-').unwrap_or((&stem, ""));
+    let id = raw_id.parse::<u32>().ok();
+    if id.is_none() {
+        diagnostics.error(
+            file_path,
+            None,
+            format!("invalid id `{raw_id}` in filename, expected `<id>-<yyyymmdd>-<slug>.md`"),
+        );
+    }
+    let (raw_date, file_slug) = match rest.split_once('-') {
+        Some((date, slug)) if is_date_segment(date) => (Some(date), slug),
+        None if is_date_segment(rest) => (Some(rest), ""),
+        _ => (None, rest),
+    };
+    let file_date = raw_date.and_then(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok());
+
+    let markdown = match fs::read_to_string(file_path) {
+        Ok(markdown) => markdown,
+        Err(e) => {
+            diagnostics.error(file_path, None, format!("unreadable file: {e}"));
+            return None;
+        }
+    };
+    let (front_matter, body, body_line) = match split_front_matter(&markdown) {
+        Ok(split) => split,
+        Err(e) => {
+            diagnostics.error(file_path, e.line, e.message);
+            return None;
+        }
+    };
+
+    // Date: front matter first, then filename
+    let date = front_matter.date.or(file_date);
+    match (date, raw_date) {
+        (Some(_), _) => {}
+        (None, Some(raw_date)) => diagnostics.error(
+            file_path,
+            None,
+            format!("invalid date `{raw_date}` in filename, expected YYYYMMDD"),
+        ),
+        (None, None) => diagnostics.error(
+            file_path,
+            None,
+            "missing date: expected `<id>-<yyyymmdd>-<slug>.md` or a front matter `date`",
+        ),
+    }
+
+    // Title: front matter first, then the leading `# ` heading
+    let first_line = body
+        .lines()
+        .enumerate()
+        .find(|(_, line)| !line.trim().is_empty());
+    let heading = first_line.and_then(|(_, line)| line.strip_prefix("# "));
+    let title = front_matter
+        .title
+        .or_else(|| heading.map(|heading| heading.trim().to_string()))
+        .filter(|title| !title.trim().is_empty());
+    if title.is_none() {
+        let line = first_line.map_or(body_line, |(index, _)| body_line + index);
+        diagnostics.error(
+            file_path,
+            Some(line),
+            "missing title: expected a front matter `title` or a leading `# ` heading",
+        );
+    }
+
+    // Body: anything besides the title heading
+    let has_body = body
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .nth(usize::from(heading.is_some()))
+        .is_some();
+    if !has_body {
+        diagnostics.error(file_path, Some(body_line), "empty body");
+    }
+
+    // Tags: normalised so `Personal Finance` and `personal-finance` are the same tag
+    let mut tags: Vec<String> = Vec::new();
+    for tag in &front_matter.tags {
+        let tag = normalize_tag(tag);
+        if tag.is_empty() {
+            diagnostics.error(file_path, None, "empty tag in front matter `tags`");
+        } else if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    // Slug: front matter first, then filename - it is the URL of the entry eg: /posts/<slug>
+    let slug = front_matter.slug.unwrap_or_else(|| file_slug.to_string());
+    if slug.is_empty() {
+        diagnostics.error(
+            file_path,
+            None,
+            "missing slug: expected `<id>-<yyyymmdd>-<slug>.md` or a front matter `slug`",
+        );
+    } else if !is_valid_slug(&slug) {
+        diagnostics.error(
+            file_path,
+            None,
+            format!("invalid slug `{slug}`, expected lowercase letters, digits and hyphens"),
+        );
+    } else if slug.bytes().all(|b| b.is_ascii_digit()) {
+        // Numeric paths are taken as ids and redirected to the slug URL
+        diagnostics.error(
+            file_path,
+            None,
+            format!("slug `{slug}` must not be only digits"),
+        );
+    }
+
+    if diagnostics.error_count() > errors_before {
+        return None;
+    }
+
+    let (html, stats, headings, links) = markdown_to_html(body, file_path, diagnostics);
+    if diagnostics.error_count() > errors_before {
+        return None;
+    }
+    let prose = if heading.is_some() {
+        body.trim_start()
+            .split_once('\n')
+            .map_or("", |(_, rest)| rest)
+    } else {
+        body
+    };
+    let description = match &front_matter.summary {
+        Some(summary) => truncate_words(summary.trim(), DESCRIPTION_CHARS),
+        None => truncate_words(&first_paragraph(prose), DESCRIPTION_CHARS),
+    };
+    let text = plain_text(prose);
+    Some(Content {
+        source: file_path.to_path_buf(),
+        id: id?,
+        date: date?,
+        title: title?,
+        slug,
+        summary: front_matter.summary,
+        tags,
+        author: front_matter.author,
+        cover_image: front_matter.cover_image,
+        draft: front_matter.draft,
+        publish_at: front_matter
+            .publish_at
+            .map(|publish_at| publish_at.timestamp()),
+        html,
+        headings,
+        word_count: stats.word_count,
+        reading_minutes: stats.reading_minutes,
+        description,
+        text,
+        related: Vec::new(),
+        links,
+    })
+}
+
+// An eight digit filename segment is taken as the date, so typos are reported rather than
+// silently becoming part of the slug
+fn is_date_segment(segment: &str) -> bool {
+    segment.len() == 8 && segment.bytes().all(|b| b.is_ascii_digit())
+}
+
+// Slugs are used verbatim in URLs
+fn is_valid_slug(slug: &str) -> bool {
+    slug.bytes()
+        .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+// Lowercase and hyphenate a tag so it can be used in URLs eg: /tags/personal-finance
+fn normalize_tag(tag: &str) -> String {
+    tag.split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase()
+}
+
+// Prose of the markdown as plain text, leaving out code blocks and markup
+fn plain_text(markdown: &str) -> String {
+    let mut in_code_block = false;
+    let mut text = String::new();
+    for event in Parser::new_ext(
+        markdown,
+        Options::ENABLE_HEADING_ATTRIBUTES | Options::ENABLE_GFM,
+    ) {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::Text(t) | Event::Code(t) if !in_code_block => text.push_str(&t),
+            Event::SoftBreak
+            | Event::HardBreak
+            | Event::End(
+                TagEnd::Paragraph | TagEnd::Heading(_) | TagEnd::Item | TagEnd::TableCell,
+            ) if !text.ends_with(' ') => text.push(' '),
+            _ => {}
+        }
+    }
+    text.trim().to_string()
+}
+
+// Plain text of the first paragraph, lines joined with spaces
+fn first_paragraph(markdown: &str) -> String {
+    let mut paragraph = String::new();
+    let mut in_paragraph = false;
+    for event in Parser::new_ext(
+        markdown,
+        Options::ENABLE_HEADING_ATTRIBUTES | Options::ENABLE_GFM,
+    ) {
+        match event {
+            Event::Start(Tag::Paragraph) => in_paragraph = true,
+            Event::End(TagEnd::Paragraph) if in_paragraph => break,
+            Event::Text(t) | Event::Code(t) if in_paragraph => paragraph.push_str(&t),
+            Event::SoftBreak | Event::HardBreak if in_paragraph => paragraph.push(' '),
+            _ => {}
+        }
+    }
+    paragraph.trim().to_string()
+}
+
+// Cut the text to at most `max_chars`, at a word boundary, marking the cut with an ellipsis
+fn truncate_words(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut truncated = String::new();
+    for word in text.split_whitespace() {
+        if truncated.chars().count() + word.chars().count() + 1 > max_chars - 1 {
+            break;
+        }
+        if !truncated.is_empty() {
+            truncated.push(' ');
+        }
+        truncated.push_str(word);
+    }
+    truncated.push('…');
+    truncated
+}
+
+// Render the markdown, counting words for the reading time and collecting the headings
+// for the table of contents in the same pass
+// `{#id}` after a heading pins its anchor id
+fn markdown_to_html(
+    markdown: &str,
+    file_path: &Path,
+    diagnostics: &mut Diagnostics,
+) -> (String, ReadingStats, Vec<Heading>, Vec<String>) {
     let options = Options::ENABLE_TABLES
         | Options::ENABLE_FOOTNOTES
         | Options::ENABLE_STRIKETHROUGH
-        | Options::ENABLE_TASKLISTS;
-    let parser = Parser::new_ext(markdown, options);
-    let events = highlight(parser).into_iter();
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_HEADING_ATTRIBUTES
+        | Options::ENABLE_MATH
+        | Options::ENABLE_GFM;
+    let mut counter = WordCounter::default();
+    let mut math_errors = Vec::new();
+    let parser = Parser::new_ext(markdown, options)
+        .inspect(|event| counter.observe(event))
+        .map(|event| render_math(event, &mut math_errors))
+        .map(render_callout);
+    let mut diagram_failures = Vec::new();
+    let (events, headings) = add_heading_ids(highlight(parser, &mut diagram_failures));
+    let mut image_errors = Vec::new();
+    let base_dir = file_path.parent().unwrap_or(Path::new("."));
+    let events = rewrite_images(events, base_dir, &mut image_errors);
+    let (events, links) = rewrite_links(events);
     let mut html = String::new();
-    html::push_html(&mut html, events);
+    html::push_html(&mut html, events.into_iter());
+
+    for error in math_errors.into_iter().chain(image_errors) {
+        diagnostics.error(file_path, None, error);
+    }
+    // Diagrams that fail to render are shown as source, so they do not fail the build
+    for failure in diagram_failures {
+        println!(
+            "cargo::warning={}: diagram shown as source: {failure}",
+            file_path.display()
+        );
+    }
 
-    html
+    (html, counter.stats(), headings, links)
 }
 
-fn highlight(parser: Parser) -> Vec<Event> {
-    let syntax_set = SyntaxSet::load_defaults_newlines();
-    let plain_text = syntax_set.find_syntax_plain_text();
-    let mut syntax = plain_text;
-    let theme = &ThemeSet::load_defaults().themes["base16-ocean.dark"];
+fn highlight<'a>(
+    parser: impl Iterator<Item = Event<'a>>,
+    diagram_failures: &mut Vec<String>,
+) -> Vec<Event<'a>> {
+    let mut fence = FenceInfo::default();
+    let mut diagrams = 0;
     let mut events = Vec::new();
     let mut to_highlight = String::new();
     let mut in_code_block = false;
 
     parser.into_iter().for_each(|event| match event {
         Event::Start(Tag::CodeBlock(kind)) => {
-            match kind {
-                CodeBlockKind::Fenced(lang) => {
-                    syntax = syntax_set.find_syntax_by_token(&lang).unwrap_or(plain_text)
-                }
-                CodeBlockKind::Indented => {}
-            }
+            fence = match kind {
+                CodeBlockKind::Fenced(info) => FenceInfo::parse(&info),
+                CodeBlockKind::Indented => FenceInfo::default(),
+            };
             in_code_block = true;
         }
         Event::End(TagEnd::CodeBlock) => {
             if in_code_block {
-                // Not using highlighted_html_for_string() because we need to inject the <code> tag
-                // for PicoCSS to apply the correct margin
-                let mut highlighter = HighlightLines::new(syntax, theme);
-                let (mut html, background) = start_highlighted_html_snippet(theme);
-
-                html.push_str("<code>");
-                for line in LinesWithEndings::from(&to_highlight) {
-                    let regions = highlighter.highlight_line(line, &syntax_set).unwrap();
-                    append_highlighted_html_for_styled_line(
-                        &regions[..],
-                        IncludeBackground::IfDifferent(background),
-                        &mut html,
-                    )
-                    .unwrap();
-                }
-                html.push_str("</code></pre>\n");
-
+                // Colours come from classes styled by the generated syntax stylesheet, so they
+                // follow the light/dark theme. Diagrams fall back to their highlighted source
+                let diagram = is_diagram(&fence).then(|| {
+                    diagrams += 1;
+                    render_diagram(&to_highlight, &fence, diagrams)
+                });
+                let html = match diagram {
+                    Some(Ok(svg)) => svg,
+                    Some(Err(e)) => {
+                        diagram_failures.push(e);
+                        render_code_block(&to_highlight, &fence)
+                    }
+                    None => render_code_block(&to_highlight, &fence),
+                };
                 events.push(Event::Html(CowStr::from(html)));
                 to_highlight.clear();
                 in_code_block = false;
@@ -158,3 +587,140 @@ fn highlight(parser: Parser) -> Vec<Event> {
 
     events
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Content directory with the given files, removed when dropped
+    struct ContentDir(PathBuf);
+
+    impl ContentDir {
+        fn new(name: &str, files: &[(&str, &str)]) -> Self {
+            let dir = env::temp_dir().join(format!("blog-{name}-{}", std::process::id()));
+            fs::create_dir_all(&dir).unwrap();
+            for (file, markdown) in files {
+                fs::write(dir.join(file), markdown).unwrap();
+            }
+            ContentDir(dir)
+        }
+    }
+
+    impl Drop for ContentDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    // File name, line and message of an error
+    type Reported = (String, Option<usize>, String);
+
+    // Ids of the listed entries and the errors of the files
+    fn list_errors(name: &str, files: &[(&str, &str)]) -> (Vec<u32>, Vec<Reported>) {
+        let dir = ContentDir::new(name, files);
+        let mut diagnostics = Diagnostics::default();
+        let entries = list_from_md_files(&dir.0, &mut diagnostics);
+        let errors = diagnostics
+            .errors()
+            .iter()
+            .map(|error| {
+                let file = Path::new(&error.file)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy();
+                (file.to_string(), error.line, error.message.clone())
+            })
+            .collect();
+        (entries.iter().map(|entry| entry.id).collect(), errors)
+    }
+
+    #[test]
+    fn reports_invalid_content_files() {
+        let (ids, errors) = list_errors(
+            "invalid",
+            &[
+                ("0001-20250101-valid.md", "# Valid\n\nBody\n"),
+                ("abc-20250101-bad-id.md", "# Bad id\n\nBody\n"),
+                ("0002-20251399-bad-date.md", "# Bad date\n\nBody\n"),
+                (
+                    "0003-20250101-no-title.md",
+                    "---\ntags: [rust]\n---\n\nJust text\n",
+                ),
+                ("0004-20250101-empty.md", "---\ntitle: Empty\n---\n"),
+                (
+                    "0005-20250101-yaml.md",
+                    "---\ntitle: [Unclosed\n---\nBody\n",
+                ),
+                (
+                    "0006-20250101-toml.md",
+                    "+++\ntitle = Unquoted\n+++\nBody\n",
+                ),
+            ],
+        );
+
+        assert_eq!(ids, [1]);
+        let found: Vec<_> = errors
+            .iter()
+            .map(|(file, line, message)| {
+                let message = message.split([':', ',']).next().unwrap();
+                (file.as_str(), *line, message)
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (
+                    "0002-20251399-bad-date.md",
+                    None,
+                    "invalid date `20251399` in filename"
+                ),
+                ("0003-20250101-no-title.md", Some(5), "missing title"),
+                ("0004-20250101-empty.md", Some(4), "empty body"),
+                (
+                    "0005-20250101-yaml.md",
+                    Some(2),
+                    "unparseable YAML front matter"
+                ),
+                (
+                    "0006-20250101-toml.md",
+                    Some(2),
+                    "unparseable TOML front matter"
+                ),
+                (
+                    "abc-20250101-bad-id.md",
+                    None,
+                    "invalid id `abc` in filename"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_duplicate_ids_and_slugs() {
+        let (ids, errors) = list_errors(
+            "duplicates",
+            &[
+                ("0001-20250101-first.md", "# First\n\nBody\n"),
+                ("0001-20250102-second.md", "# Second\n\nBody\n"),
+                ("0002-20250103-first.md", "# Third\n\nBody\n"),
+            ],
+        );
+
+        assert_eq!(ids, [1]);
+        assert_eq!(
+            errors,
+            [
+                (
+                    "0001-20250102-second.md".to_string(),
+                    None,
+                    "duplicate id 1 (already used by 0001-20250101-first.md)".to_string()
+                ),
+                (
+                    "0002-20250103-first.md".to_string(),
+                    None,
+                    "duplicate slug `first` (already used by 0001-20250101-first.md)".to_string()
+                ),
+            ]
+        );
+    }
+}
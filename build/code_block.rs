@@ -0,0 +1,217 @@
+use crate::syntax_theme::{CLASS_STYLE, syntax_set};
+use std::ops::RangeInclusive;
+use syntect::{
+    html::line_tokens_to_classed_spans,
+    parsing::{ParseState, Scope, ScopeStack},
+    util::LinesWithEndings,
+};
+
+// Options of a fenced code block, from its info string eg: ```sql title="migrations/x.sql"
+// or ```rust {3,5-7} linenos
+// Rustdoc-style flags after the language (```rust,ignore) are accepted and ignored
+#[derive(Debug, Default)]
+pub struct FenceInfo {
+    pub lang: String,
+    pub title: Option<String>,
+    pub line_numbers: bool,
+    // 1-based line ranges to highlight
+    pub highlighted: Vec<RangeInclusive<usize>>,
+}
+
+impl FenceInfo {
+    pub fn parse(info: &str) -> Self {
+        let mut fence = FenceInfo::default();
+        let mut linenos = false;
+        for (index, token) in fence_tokens(info).into_iter().enumerate() {
+            if let Some(ranges) = token.strip_prefix('{').and_then(|t| t.strip_suffix('}')) {
+                fence
+                    .highlighted
+                    .extend(ranges.split(',').filter_map(line_range));
+                continue;
+            }
+            match token.split_once('=') {
+                Some(("title", title)) => fence.title = Some(title.to_string()),
+                Some(_) => {}
+                None if token == "linenos" => linenos = true,
+                None if index == 0 => fence.lang = token,
+                None => {}
+            }
+        }
+        // Titled blocks are file listings, numbered to be referred to by line
+        fence.line_numbers = linenos || fence.title.is_some();
+        fence
+    }
+
+    fn is_highlighted(&self, line: usize) -> bool {
+        self.highlighted.iter().any(|range| range.contains(&line))
+    }
+}
+
+// `5` or `5-7` - malformed ranges are ignored
+fn line_range(range: &str) -> Option<RangeInclusive<usize>> {
+    let range = range.trim();
+    match range.split_once('-') {
+        Some((start, end)) => Some(start.trim().parse().ok()?..=end.trim().parse().ok()?),
+        None => {
+            let line = range.parse().ok()?;
+            Some(line..=line)
+        }
+    }
+}
+
+// Words of the info string, split on whitespace and commas outside double quotes and braces
+// A brace group is its own word even when attached, eg: rust{3}
+fn fence_tokens(info: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut braced = false;
+    for c in info.chars() {
+        match c {
+            '"' if !braced => quoted = !quoted,
+            '{' if !quoted => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                braced = true;
+                current.push(c);
+            }
+            '}' if braced => {
+                braced = false;
+                current.push(c);
+                tokens.push(std::mem::take(&mut current));
+            }
+            c if !quoted && !braced && (c.is_whitespace() || c == ',') => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+// Highlighted <pre><code> block with every line in its own `.code-line` span, so lines can be
+// numbered and highlighted. The newline is kept outside the span, so highlighted lines can be
+// full width. Not `.line`: scopes such as `comment.line` are written as that class too
+// Titled blocks are wrapped in a <figure> captioned with the title
+// The <code> tag is needed for PicoCSS to apply the correct margin
+pub fn render_code_block(code: &str, fence: &FenceInfo) -> String {
+    let syntax_set = syntax_set();
+    let syntax = syntax_set
+        .find_syntax_by_token(&fence.lang)
+        .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+    let mut parse_state = ParseState::new(syntax);
+    let mut scope_stack = ScopeStack::new();
+
+    let mut lines = String::new();
+    for (index, line) in LinesWithEndings::from(code).enumerate() {
+        let ops = parse_state.parse_line(line, syntax_set).unwrap();
+        if fence.is_highlighted(index + 1) {
+            lines.push_str(r#"<span class="code-line highlighted">"#);
+        } else {
+            lines.push_str(r#"<span class="code-line">"#);
+        }
+        // Scopes still open from the previous lines are reopened, so every line is balanced
+        for scope in scope_stack.as_slice() {
+            lines.push_str(&format!(r#"<span class="{}">"#, scope_classes(*scope)));
+        }
+        let (mut html, _) =
+            line_tokens_to_classed_spans(line, &ops, CLASS_STYLE, &mut scope_stack).unwrap();
+        // Only text can hold the newline, markup never does
+        let newline = html.rfind('\n').map(|at| html.remove(at));
+        lines.push_str(&html);
+        lines.push_str(&"</span>".repeat(scope_stack.len()));
+        lines.push_str("</span>");
+        lines.extend(newline);
+    }
+
+    let class = if fence.line_numbers {
+        "code line-numbers"
+    } else {
+        "code"
+    };
+    let pre = format!(r#"<pre class="{class}"><code>{lines}</code></pre>"#);
+    match &fence.title {
+        Some(title) => format!(
+            "<figure class=\"code-block\"><figcaption>{}</figcaption>{pre}</figure>\n",
+            escape_html(title)
+        ),
+        None => format!("{pre}\n"),
+    }
+}
+
+// Classes of a scope as `line_tokens_to_classed_spans` writes them with `ClassStyle::Spaced`
+fn scope_classes(scope: Scope) -> String {
+    scope.build_string().replace('.', " ")
+}
+
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_language_and_title() {
+        let fence = FenceInfo::parse(r#"sql title="migrations/0001 init.sql""#);
+        assert_eq!(fence.lang, "sql");
+        assert_eq!(fence.title.as_deref(), Some("migrations/0001 init.sql"));
+        // Titled blocks are numbered
+        assert!(fence.line_numbers);
+
+        let fence = FenceInfo::parse("rust,ignore");
+        assert_eq!(fence.lang, "rust");
+        assert_eq!(fence.title, None);
+        assert!(!fence.line_numbers);
+
+        assert_eq!(FenceInfo::parse("").lang, "");
+    }
+
+    #[test]
+    fn parses_highlighted_lines_and_line_numbers() {
+        let fence = FenceInfo::parse("rust {3,5-7} linenos");
+        assert_eq!(fence.lang, "rust");
+        assert_eq!(fence.highlighted, [3..=3, 5..=7]);
+        assert!(fence.line_numbers);
+        assert!(!fence.is_highlighted(4) && fence.is_highlighted(6));
+
+        // Attached to the language, with a title and malformed ranges left out
+        let fence = FenceInfo::parse(r#"toml{1, 2-x ,4-4} title="a{b}.toml""#);
+        assert_eq!(fence.lang, "toml");
+        assert_eq!(fence.title.as_deref(), Some("a{b}.toml"));
+        assert_eq!(fence.highlighted, [1..=1, 4..=4]);
+    }
+
+    #[test]
+    fn numbers_every_line_of_a_commented_block() {
+        let fence = FenceInfo::parse(r#"toml title="Cargo.toml""#);
+        let code = "# Dependencies\n[dependencies]\nserde = \"1\" # derive\n";
+        let html = render_code_block(code, &fence);
+
+        assert!(html.starts_with(
+            r#"<figure class="code-block"><figcaption>Cargo.toml</figcaption><pre class="code line-numbers">"#
+        ));
+        assert_eq!(html.matches(r#"<span class="code-line">"#).count(), 3);
+        // Line comments are highlighted with the classes `comment line`, not numbered
+        assert!(html.contains(r#"<span class="comment line"#));
+        assert!(!html.contains(r#"class="line"#));
+
+        let fence = FenceInfo::parse("toml {2}");
+        let html = render_code_block(code, &fence);
+        assert_eq!(
+            html.matches(r#"<span class="code-line highlighted">"#)
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use std::{env, fmt, path::Path, process};
+
+// Set to `1`/`true` to turn content errors into warnings and skip the offending files
+const LENIENT_ENV: &str = "BLOG_LENIENT_CONTENT";
+
+// A problem found in a content file, pointing at the line when it is known
+#[derive(Debug)]
+pub struct ContentError {
+    pub file: String,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ContentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}", self.file, line, self.message),
+            None => write!(f, "{}: {}", self.file, self.message),
+        }
+    }
+}
+
+// Collects every content error so they can be reported at once instead of one per build
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    errors: Vec<ContentError>,
+}
+
+impl Diagnostics {
+    pub fn error(&mut self, file: &Path, line: Option<usize>, message: impl Into<String>) {
+        self.errors.push(ContentError {
+            file: display_path(file),
+            line,
+            message: message.into(),
+        });
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.errors.len()
+    }
+
+    #[cfg(test)]
+    pub fn errors(&self) -> &[ContentError] {
+        &self.errors
+    }
+
+    // Fail the build with the full report, or only warn in lenient mode
+    pub fn report(self) {
+        println!("cargo::rerun-if-env-changed={LENIENT_ENV}");
+        if self.errors.is_empty() {
+            return;
+        }
+
+        if is_lenient() {
+            for error in &self.errors {
+                println!("cargo::warning={error}");
+            }
+            return;
+        }
+
+        eprintln!(
+            "error: {} problem(s) found in content files:",
+            self.errors.len()
+        );
+        for error in &self.errors {
+            eprintln!("  {error}");
+        }
+        eprintln!(
+            "hint: set {LENIENT_ENV}=1 to skip invalid files with a warning instead (dead links are only warned about)"
+        );
+        process::exit(1);
+    }
+}
+
+fn is_lenient() -> bool {
+    env::var(LENIENT_ENV).is_ok_and(|value| matches!(value.as_str(), "1" | "true"))
+}
+
+fn display_path(file: &Path) -> String {
+    let path = file.display().to_string();
+    path.strip_prefix("./").map(str::to_string).unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_the_file_and_line() {
+        let mut diagnostics = Diagnostics::default();
+        diagnostics.error(
+            Path::new("./contents/posts/0001-a.md"),
+            Some(3),
+            "empty body",
+        );
+        diagnostics.error(Path::new("contents/poems/x.md"), None, "invalid id `x`");
+
+        assert_eq!(diagnostics.error_count(), 2);
+        let report: Vec<_> = diagnostics
+            .errors()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            report,
+            [
+                "contents/posts/0001-a.md:3: empty body",
+                "contents/poems/x.md: invalid id `x`"
+            ]
+        );
+    }
+}
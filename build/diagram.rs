@@ -0,0 +1,53 @@
+use crate::code_block::{FenceInfo, escape_html};
+use layout::{
+    backends::svg::SVGWriter,
+    gv::{DotParser, GraphBuilder},
+};
+use std::panic::{self, AssertUnwindSafe};
+
+// Fence languages drawn as diagrams instead of highlighted
+const DIAGRAM_LANGS: &[&str] = &["dot", "graphviz"];
+
+pub fn is_diagram(fence: &FenceInfo) -> bool {
+    DIAGRAM_LANGS.contains(&fence.lang.as_str())
+}
+
+// Lay out a Graphviz graph as an inline SVG <figure>, captioned with the fence title
+// The ids of the SVG are prefixed with the diagram index, so several diagrams can share a page
+pub fn render_diagram(source: &str, fence: &FenceInfo, index: usize) -> Result<String, String> {
+    let graph = DotParser::new(source)
+        .process()
+        .map_err(|e| format!("invalid graph: {e}"))?;
+
+    // The layout engine panics on some graphs it does not support - silence the report
+    // while it runs, the failure is returned instead
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let svg = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut builder = GraphBuilder::new();
+        builder.visit_graph(&graph);
+        let mut visual_graph = builder.get();
+        let mut writer = SVGWriter::new();
+        visual_graph.do_it(false, false, false, &mut writer);
+        writer.finalize()
+    }));
+    panic::set_hook(default_hook);
+    let svg = svg.map_err(|_| "the layout engine does not support this graph".to_string())?;
+
+    // The XML declaration is not allowed inline in HTML
+    let svg = svg.find("<svg").map_or(svg.as_str(), |start| &svg[start..]);
+    let prefix = format!("diagram-{index}-");
+    let svg = svg
+        .replace(r#"id=""#, &format!(r#"id="{prefix}"#))
+        .replace("url(#", &format!("url(#{prefix}"))
+        .replace(r##"href="#"##, &format!(r##"href="#{prefix}"##));
+
+    let caption = fence
+        .title
+        .as_ref()
+        .map(|title| format!("<figcaption>{}</figcaption>", escape_html(title)))
+        .unwrap_or_default();
+    Ok(format!(
+        "<figure class=\"diagram\">{svg}{caption}</figure>\n"
+    ))
+}
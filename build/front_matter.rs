@@ -0,0 +1,250 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{
+    Deserialize, Deserializer,
+    de::{self, MapAccess, Visitor, value::MapAccessDeserializer},
+};
+use std::fmt;
+
+// Optional metadata block at the top of a content file.
+// YAML is fenced by `---` lines and TOML by `+++` lines (Hugo convention).
+// Every field is optional - values derived from the filename are used as fallback.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct FrontMatter {
+    pub title: Option<String>,
+    #[serde(deserialize_with = "deserialize_date")]
+    pub date: Option<NaiveDate>,
+    pub tags: Vec<String>,
+    pub summary: Option<String>,
+    pub draft: bool,
+    // Hidden from lists, feeds and sitemaps until this time - times without offset are UTC
+    #[serde(deserialize_with = "deserialize_datetime")]
+    pub publish_at: Option<DateTime<Utc>>,
+    pub slug: Option<String>,
+    pub author: Option<String>,
+    #[serde(alias = "cover")]
+    pub cover_image: Option<String>,
+}
+
+// Front matter error with the line it points at, counted from the top of the file
+#[derive(Debug)]
+pub struct FrontMatterError {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+// Split the front matter from the markdown body
+// Also returns the line number the body starts at, for diagnostics
+// Files without a front matter block get the default (empty) front matter
+pub fn split_front_matter(markdown: &str) -> Result<(FrontMatter, &str, usize), FrontMatterError> {
+    let Some((fence, rest)) = ["---", "+++"]
+        .iter()
+        .find_map(|fence| Some((*fence, strip_fence_line(markdown, fence)?)))
+    else {
+        return Ok((FrontMatter::default(), markdown, 1));
+    };
+
+    // Find the closing fence on a line of its own
+    let mut offset = 0;
+    for (index, line) in rest.split_inclusive('\n').enumerate() {
+        if line.trim_end() == fence {
+            let raw = &rest[..offset];
+            let body = &rest[offset + line.len()..];
+            // Lines inside the block are shifted by the opening fence
+            let to_file_line = |line: usize| line + 1;
+            let front_matter = match fence {
+                "---" => serde_yaml::from_str(raw).map_err(|e| FrontMatterError {
+                    line: e.location().map(|location| to_file_line(location.line())),
+                    message: format!("unparseable YAML front matter: {e}"),
+                }),
+                _ => toml::from_str(raw).map_err(|e| FrontMatterError {
+                    line: e
+                        .span()
+                        .map(|span| to_file_line(raw[..span.start].matches('\n').count() + 1)),
+                    message: format!("unparseable TOML front matter: {}", e.message()),
+                }),
+            }?;
+            // Opening fence + block + closing fence
+            return Ok((front_matter, body, index + 3));
+        }
+        offset += line.len();
+    }
+
+    Err(FrontMatterError {
+        line: Some(1),
+        message: format!("front matter opened with `{fence}` is never closed"),
+    })
+}
+
+fn strip_fence_line<'a>(markdown: &'a str, fence: &str) -> Option<&'a str> {
+    let rest = markdown.strip_prefix(fence)?;
+    rest.strip_prefix("\r\n")
+        .or_else(|| rest.strip_prefix('\n'))
+}
+
+// YAML gives dates as plain strings while TOML has a native date type
+fn deserialize_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(DateVisitor {
+        expecting: "a YYYY-MM-DD date",
+        parse: |raw| match raw {
+            RawDate::Text(text) => NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d")
+                .map_err(|e| format!("invalid date `{text}`: {e}")),
+            RawDate::Toml(datetime) => datetime
+                .date
+                .and_then(|d| NaiveDate::from_ymd_opt(d.year.into(), d.month.into(), d.day.into()))
+                .ok_or_else(|| format!("invalid date `{datetime}`")),
+        },
+    })
+}
+
+// Accepts RFC 3339 (`2025-10-01T09:00:00+07:00`), a time without offset taken as UTC,
+// or a plain date taken as midnight UTC - from YAML strings or TOML datetimes alike
+fn deserialize_datetime<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(DateVisitor {
+        expecting: "a date and time",
+        parse: |raw| {
+            let text = match raw {
+                RawDate::Text(text) => text.trim().to_string(),
+                RawDate::Toml(datetime) => datetime.to_string(),
+            };
+            DateTime::parse_from_rfc3339(&text)
+                .map(|datetime| datetime.to_utc())
+                .ok()
+                .or_else(|| {
+                    ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+                        .iter()
+                        .find_map(|format| NaiveDateTime::parse_from_str(&text, format).ok())
+                        .map(|datetime| datetime.and_utc())
+                })
+                .or_else(|| {
+                    NaiveDate::parse_from_str(&text, "%Y-%m-%d")
+                        .ok()
+                        .map(|date| date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+                })
+                .ok_or_else(|| format!("invalid date and time `{text}`"))
+        },
+    })
+}
+
+enum RawDate {
+    Text(String),
+    Toml(toml::value::Datetime),
+}
+
+// Dates are read with a visitor rather than an untagged enum: errors raised while the value is
+// visited keep its position, so YAML errors point at the line of the date
+struct DateVisitor<T> {
+    expecting: &'static str,
+    parse: fn(RawDate) -> Result<T, String>,
+}
+
+impl<'de, T> Visitor<'de> for DateVisitor<T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Self::Value, E> {
+        (self.parse)(RawDate::Text(text.to_string()))
+            .map(Some)
+            .map_err(E::custom)
+    }
+
+    // TOML datetimes are given as a map
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let datetime = toml::value::Datetime::deserialize(MapAccessDeserializer::new(map))?;
+        (self.parse)(RawDate::Toml(datetime))
+            .map(Some)
+            .map_err(de::Error::custom)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_line(markdown: &str) -> Option<usize> {
+        split_front_matter(markdown).unwrap_err().line
+    }
+
+    #[test]
+    fn body_starts_after_the_front_matter() {
+        let (front_matter, body, body_line) = split_front_matter("# Title\n\nBody\n").unwrap();
+        assert!(front_matter.title.is_none());
+        assert_eq!((body, body_line), ("# Title\n\nBody\n", 1));
+
+        let yaml = "---\ntitle: Hello\ntags: [rust, leptos]\n---\nBody\n";
+        let (front_matter, body, body_line) = split_front_matter(yaml).unwrap();
+        assert_eq!(front_matter.title.as_deref(), Some("Hello"));
+        assert_eq!(front_matter.tags, ["rust", "leptos"]);
+        assert_eq!((body, body_line), ("Body\n", 5));
+
+        let toml = "+++\r\ntitle = \"Hello\"\r\ndate = 2025-09-05\r\n+++\r\nBody\r\n";
+        let (front_matter, body, body_line) = split_front_matter(toml).unwrap();
+        assert_eq!(front_matter.date, NaiveDate::from_ymd_opt(2025, 9, 5));
+        assert_eq!((body, body_line), ("Body\r\n", 5));
+    }
+
+    #[test]
+    fn reads_yaml_and_toml_dates() {
+        let expected = DateTime::parse_from_rfc3339("2025-10-01T02:00:00Z").unwrap();
+
+        let yaml = "---\ndate: 2025-09-05\npublish_at: 2025-10-01T09:00:00+07:00\n---\n";
+        let (front_matter, _, _) = split_front_matter(yaml).unwrap();
+        assert_eq!(front_matter.date, NaiveDate::from_ymd_opt(2025, 9, 5));
+        assert_eq!(front_matter.publish_at, Some(expected.to_utc()));
+
+        let toml = "+++\ndate = 2025-09-05T08:00:00Z\npublish_at = 2025-10-01T02:00:00\n+++\n";
+        let (front_matter, _, _) = split_front_matter(toml).unwrap();
+        assert_eq!(front_matter.date, NaiveDate::from_ymd_opt(2025, 9, 5));
+        assert_eq!(front_matter.publish_at, Some(expected.to_utc()));
+
+        let (front_matter, _, _) = split_front_matter("---\ndate:\n---\n").unwrap();
+        assert!(front_matter.date.is_none());
+    }
+
+    #[test]
+    fn errors_point_at_the_file_line() {
+        assert_eq!(
+            error_line("---\ntitle: Hello\ntags: [rust\n---\nBody\n"),
+            Some(4)
+        );
+        assert_eq!(
+            error_line("---\ntitle: Hello\ndate: 2025-13-01\n---\n"),
+            Some(3)
+        );
+        assert_eq!(
+            error_line("---\ntitle: Hello\n\npublish_at: soon\n---\n"),
+            Some(4)
+        );
+        assert_eq!(
+            error_line("+++\ntitle = \"Hello\"\ndate = nope\n+++\n"),
+            Some(3)
+        );
+        assert_eq!(
+            error_line("+++\ntitle = \"Hello\"\ndate = \"2025-13-01\"\n+++\n"),
+            Some(3)
+        );
+        // Never closed
+        assert_eq!(error_line("---\ntitle: Hello\n\nBody\n"), Some(1));
+    }
+}
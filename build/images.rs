@@ -0,0 +1,375 @@
+use crate::code_block::escape_html;
+use image::{DynamicImage, ImageFormat, ImageReader, imageops::FilterType};
+use pulldown_cmark::{CowStr, Event, Tag, TagEnd};
+use std::{
+    env, fs,
+    hash::{DefaultHasher, Hash, Hasher},
+    io::Cursor,
+    path::{Path, PathBuf},
+    process,
+};
+
+// Processed images are written to MEDIA_DIR of the site root, next to the assets
+// cargo-leptos copies there, eg: LEPTOS_SITE_ROOT=/app/site
+const SITE_ROOT_ENV: &str = "LEPTOS_SITE_ROOT";
+const DEFAULT_SITE_ROOT: &str = "target/site";
+const MEDIA_DIR: &str = "media";
+
+// Widths of the resized variants, smaller than the original only
+const VARIANT_WIDTHS: &[u32] = &[480, 960, 1440];
+
+// Displayed width of an image in the content column, for the browser to pick a variant,
+// and the variant loaded by browsers without srcset support
+const SIZES: &str = "(min-width: 768px) 720px, 100vw";
+const DEFAULT_WIDTH: u32 = 960;
+
+// Rewrite the images of the document: local images (relative to the markdown file) are
+// copied to the site with resized and WebP variants, and rendered as <picture> with a srcset
+// and their dimensions, to avoid layout shift. Every image is lazy loaded
+// Missing or unreadable images are reported in `errors`
+pub fn rewrite_images<'a>(
+    events: Vec<Event<'a>>,
+    base_dir: &Path,
+    errors: &mut Vec<String>,
+) -> Vec<Event<'a>> {
+    let mut output = Vec::with_capacity(events.len());
+    // Source, title and alt text of the image being read
+    let mut pending: Option<(CowStr<'a>, CowStr<'a>, String)> = None;
+
+    for event in events {
+        match (event, pending.as_mut()) {
+            (
+                Event::Start(Tag::Image {
+                    dest_url, title, ..
+                }),
+                None,
+            ) => pending = Some((dest_url, title, String::new())),
+            (Event::End(TagEnd::Image), Some(_)) => {
+                let (src, title, alt) = pending.take().expect("an image is pending");
+                let html = image_html(&src, &title, &alt, base_dir, errors);
+                output.push(Event::InlineHtml(CowStr::from(html)));
+            }
+            // Formatting is dropped from the alt text, only the text is kept
+            (Event::Text(text) | Event::Code(text), Some((_, _, alt))) => alt.push_str(&text),
+            (_, Some(_)) => {}
+            (event, None) => output.push(event),
+        }
+    }
+
+    output
+}
+
+fn image_html(
+    src: &str,
+    title: &str,
+    alt: &str,
+    base_dir: &Path,
+    errors: &mut Vec<String>,
+) -> String {
+    let mut attributes = format!(r#"alt="{}""#, escape_html(alt));
+    if !title.is_empty() {
+        attributes.push_str(&format!(r#" title="{}""#, escape_html(title)));
+    }
+
+    // Remote images and site paths (eg: /favico.svg) are not processed
+    if src.contains(':') || src.starts_with('/') {
+        return format!(
+            r#"<img src="{}" {attributes} loading="lazy" decoding="async">"#,
+            escape_html(src)
+        );
+    }
+
+    match process_image(&base_dir.join(percent_decode(src))) {
+        Ok(image) => image.html(&attributes),
+        Err(e) => {
+            errors.push(format!("image `{src}`: {e}"));
+            String::new()
+        }
+    }
+}
+
+// Markdown links are URLs, eg: `my%20chart.png` is the file `my chart.png`
+fn percent_decode(src: &str) -> String {
+    let bytes = src.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| src.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+// The published files of an image, as srcset candidates
+struct ProcessedImage {
+    src: String,
+    dimensions: Option<(u32, u32)>,
+    srcset: Vec<(String, u32)>,
+    webp_srcset: Vec<(String, u32)>,
+}
+
+impl ProcessedImage {
+    fn html(&self, attributes: &str) -> String {
+        let mut img = format!(r#"<img src="{}""#, self.src);
+        if self.srcset.len() > 1 {
+            img.push_str(&format!(
+                r#" srcset="{}" sizes="{SIZES}""#,
+                srcset(&self.srcset)
+            ));
+        }
+        if let Some((width, height)) = self.dimensions {
+            img.push_str(&format!(r#" width="{width}" height="{height}""#));
+        }
+        img.push_str(&format!(
+            r#" {attributes} loading="lazy" decoding="async">"#
+        ));
+
+        if self.webp_srcset.is_empty() {
+            return img;
+        }
+        format!(
+            r#"<picture><source type="image/webp" srcset="{}" sizes="{SIZES}">{img}</picture>"#,
+            srcset(&self.webp_srcset)
+        )
+    }
+}
+
+fn srcset(candidates: &[(String, u32)]) -> String {
+    candidates
+        .iter()
+        .map(|(url, width)| format!("{url} {width}w"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Publish the image and its variants - file names carry a hash of the content, so files
+// already written by an earlier build are reused, and changed images get new URLs
+fn process_image(path: &Path) -> Result<ProcessedImage, String> {
+    let bytes = fs::read(path).map_err(|e| format!("unreadable file {}: {e}", path.display()))?;
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    let name = published_name(path, &bytes);
+    let media_dir = media_dir();
+    let original = format!("{name}.{extension}");
+    write_once(&media_dir.join(&original), |tmp| {
+        fs::write(tmp, &bytes).map_err(|e| e.to_string())
+    })?;
+
+    // Vector and animated images are published as they are
+    let format = match extension.as_str() {
+        "svg" | "gif" => {
+            let dimensions = match extension.as_str() {
+                "svg" => svg_dimensions(&String::from_utf8_lossy(&bytes)),
+                _ => ImageReader::with_format(Cursor::new(&bytes), ImageFormat::Gif)
+                    .into_dimensions()
+                    .ok(),
+            };
+            return Ok(ProcessedImage {
+                src: media_url(&original),
+                dimensions,
+                srcset: Vec::new(),
+                webp_srcset: Vec::new(),
+            });
+        }
+        _ => ImageFormat::from_extension(&extension)
+            .filter(|format| {
+                matches!(
+                    format,
+                    ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::WebP
+                )
+            })
+            .ok_or_else(|| format!("unsupported image format `{extension}`"))?,
+    };
+
+    let (width, height) = ImageReader::with_format(Cursor::new(&bytes), format)
+        .into_dimensions()
+        .map_err(|e| format!("undecodable image: {e}"))?;
+    // Decoded only when a variant has to be written
+    let mut decoded: Option<DynamicImage> = None;
+    let mut decode = || -> Result<DynamicImage, String> {
+        if decoded.is_none() {
+            let image = image::load_from_memory_with_format(&bytes, format)
+                .map_err(|e| format!("undecodable image: {e}"))?;
+            decoded = Some(image);
+        }
+        Ok(decoded.clone().expect("image is decoded"))
+    };
+
+    let mut image = ProcessedImage {
+        src: media_url(&original),
+        dimensions: Some((width, height)),
+        srcset: Vec::new(),
+        webp_srcset: Vec::new(),
+    };
+    let widths = VARIANT_WIDTHS
+        .iter()
+        .copied()
+        .filter(|variant| *variant < width)
+        .chain([width]);
+    for variant in widths {
+        let resize = |image: DynamicImage| {
+            if variant == width {
+                image
+            } else {
+                image.resize(variant, u32::MAX, FilterType::Lanczos3)
+            }
+        };
+
+        let file = if variant == width {
+            original.clone()
+        } else {
+            let file = format!("{name}-{variant}.{extension}");
+            write_once(&media_dir.join(&file), |tmp| {
+                resize(decode()?)
+                    .save_with_format(tmp, format)
+                    .map_err(|e| e.to_string())
+            })?;
+            file
+        };
+        image.srcset.push((media_url(&file), variant));
+
+        if format != ImageFormat::WebP {
+            let webp = match variant == width {
+                true => format!("{name}.webp"),
+                false => format!("{name}-{variant}.webp"),
+            };
+            // The WebP encoder only takes 8-bit RGBA
+            write_once(&media_dir.join(&webp), |tmp| {
+                DynamicImage::ImageRgba8(resize(decode()?).to_rgba8())
+                    .save_with_format(tmp, ImageFormat::WebP)
+                    .map_err(|e| e.to_string())
+            })?;
+            image.webp_srcset.push((media_url(&webp), variant));
+        }
+    }
+
+    if let Some((url, _)) = image.srcset.iter().find(|(_, w)| *w >= DEFAULT_WIDTH) {
+        image.src = url.clone();
+    }
+    Ok(image)
+}
+
+// Size of an SVG from the `width` and `height` of its root element, in pixels or unitless,
+// or else from its `viewBox`
+fn svg_dimensions(svg: &str) -> Option<(u32, u32)> {
+    let start = svg.find("<svg")?;
+    let tag = &svg[start..start + svg[start..].find('>')?];
+    let length = |name: &str| {
+        let value = svg_attribute(tag, name)?;
+        let value = value.strip_suffix("px").unwrap_or(value);
+        value
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|length| *length > 0.0)
+    };
+    let (width, height) = match (length("width"), length("height")) {
+        (Some(width), Some(height)) => (width, height),
+        _ => {
+            let view_box: Vec<f64> = svg_attribute(tag, "viewBox")?
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|value| !value.is_empty())
+                .map(|value| value.parse().ok())
+                .collect::<Option<_>>()?;
+            match view_box[..] {
+                [_, _, width, height] if width > 0.0 && height > 0.0 => (width, height),
+                _ => return None,
+            }
+        }
+    };
+    Some((width.round() as u32, height.round() as u32))
+}
+
+// Value of an attribute of a start tag, quoted with ' or "
+fn svg_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = tag;
+    while let Some(at) = rest.find(name) {
+        let preceded_by_space = rest[..at].ends_with(char::is_whitespace);
+        rest = &rest[at + name.len()..];
+        let Some(value) = rest.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let Some(quote) = value.chars().next().filter(|c| matches!(c, '"' | '\'')) else {
+            continue;
+        };
+        if preceded_by_space {
+            return value[1..].split(quote).next();
+        }
+    }
+    None
+}
+
+// Lowercase file stem with the content hash, eg: request-flow-1a2b3c4d
+fn published_name(path: &Path, bytes: &[u8]) -> String {
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default()
+        .to_lowercase()
+        .replace(|c: char| !c.is_ascii_alphanumeric(), "-");
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    format!("{stem}-{:08x}", hasher.finish() as u32)
+}
+
+// Write the file unless an earlier build did - through a temporary file, as the server
+// and WASM builds may run this script at the same time
+fn write_once(path: &Path, write: impl FnOnce(&Path) -> Result<(), String>) -> Result<(), String> {
+    if path.exists() {
+        return Ok(());
+    }
+    let tmp = path.with_extension(format!("{}.tmp", process::id()));
+    write(&tmp)
+        .and_then(|()| fs::rename(&tmp, path).map_err(|e| e.to_string()))
+        .map_err(|e| format!("failed to write {}: {e}", path.display()))
+}
+
+fn media_dir() -> PathBuf {
+    println!("cargo::rerun-if-env-changed={SITE_ROOT_ENV}");
+    let site_root = env::var(SITE_ROOT_ENV).unwrap_or_else(|_| DEFAULT_SITE_ROOT.to_string());
+    let media_dir = Path::new(&site_root).join(MEDIA_DIR);
+    fs::create_dir_all(&media_dir).unwrap();
+    // Publish again when the site root is cleaned
+    println!("cargo::rerun-if-changed={}", media_dir.display());
+    media_dir
+}
+
+fn media_url(file: &str) -> String {
+    format!("/{MEDIA_DIR}/{file}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_svg_dimensions() {
+        let svg = |tag: &str| svg_dimensions(&format!("<?xml version=\"1.0\"?>\n{tag}</svg>"));
+
+        assert_eq!(svg(r#"<svg width="120" height="80.4">"#), Some((120, 80)));
+        assert_eq!(svg(r#"<svg width='120px' height='80px'>"#), Some((120, 80)));
+        assert_eq!(svg(r#"<svg viewBox="0 0 640 480">"#), Some((640, 480)));
+        // Relative lengths are left to the viewBox
+        assert_eq!(
+            svg(r#"<svg width="100%" stroke-width="2" viewBox="0,0,300,150">"#),
+            Some((300, 150))
+        );
+        assert_eq!(svg(r#"<svg width="100%">"#), None);
+    }
+}
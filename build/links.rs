@@ -0,0 +1,205 @@
+use crate::Content;
+use crate::code_block::escape_html;
+use crate::diagnostics::Diagnostics;
+use pulldown_cmark::{CowStr, Event, Tag, TagEnd};
+use std::env;
+
+// Links to the site itself are internal - same default as `crate::shared::site::SITE_URL`
+const SITE_URL_ENV: &str = "SITE_URL";
+const DEFAULT_SITE_URL: &str = "https://williannguyen.com";
+
+// Mark external links - opened without access to this page or the referrer - and return
+// the internal links of the document, to be checked with `check_links`
+pub fn rewrite_links(events: Vec<Event<'_>>) -> (Vec<Event<'_>>, Vec<String>) {
+    let site_url = site_url();
+    let mut output = Vec::with_capacity(events.len());
+    let mut internal = Vec::new();
+    let mut in_external = false;
+
+    for event in events {
+        match event {
+            Event::Start(Tag::Link {
+                ref dest_url,
+                ref title,
+                ..
+            }) => match own_path(dest_url, &site_url) {
+                Some(path) => {
+                    internal.push(path);
+                    output.push(event);
+                }
+                None if is_external(dest_url) => {
+                    let mut html = format!(r#"<a href="{}""#, escape_html(dest_url));
+                    if !title.is_empty() {
+                        html.push_str(&format!(r#" title="{}""#, escape_html(title)));
+                    }
+                    html.push_str(r#" class="external" rel="noopener noreferrer">"#);
+                    output.push(Event::InlineHtml(CowStr::from(html)));
+                    in_external = true;
+                }
+                None => {
+                    internal.push(dest_url.to_string());
+                    output.push(event);
+                }
+            },
+            Event::End(TagEnd::Link) if in_external => {
+                output.push(Event::InlineHtml(CowStr::from(
+                    r#"<span class="external-marker" aria-hidden="true">↗</span></a>"#,
+                )));
+                in_external = false;
+            }
+            event => output.push(event),
+        }
+    }
+
+    (output, internal)
+}
+
+// Fail on internal links to an entry or heading that does not exist: `/posts/<slug or id>`,
+// `/poems/...`, with an optional `#heading`, or a `#heading` of the same entry
+// Other site paths (eg: /tags/rust) are routes of the app and are not checked
+// Sections are given with their route prefix, eg: ("posts", &posts)
+pub fn check_links(sections: &[(&str, &[Content])], diagnostics: &mut Diagnostics) {
+    for (_, entries) in sections {
+        for entry in entries.iter() {
+            for link in &entry.links {
+                if let Err(reason) = resolve_link(link, entry, sections) {
+                    diagnostics.error(&entry.source, None, format!("dead link `{link}`: {reason}"));
+                }
+            }
+        }
+    }
+}
+
+fn resolve_link(
+    link: &str,
+    entry: &Content,
+    sections: &[(&str, &[Content])],
+) -> Result<(), String> {
+    let (path, fragment) = link.split_once('#').unwrap_or((link, ""));
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+
+    let target = if path.is_empty() {
+        entry
+    } else {
+        let Some((section, key)) = path
+            .strip_prefix('/')
+            .and_then(|path| path.trim_end_matches('/').split_once('/'))
+        else {
+            return Ok(());
+        };
+        let Some((_, entries)) = sections.iter().find(|(prefix, _)| *prefix == section) else {
+            return Ok(());
+        };
+        // Old id URLs redirect to the slug, so they are valid too, padded or not (eg: /posts/0004)
+        // as with `ContentKind::entry_by_id`
+        let id = key.parse::<u32>().ok();
+        entries
+            .iter()
+            .find(|target| target.slug == key || Some(target.id) == id)
+            .ok_or_else(|| format!("no entry `{key}` in {section}"))?
+    };
+
+    if fragment.is_empty() || target.headings.iter().any(|heading| heading.id == fragment) {
+        Ok(())
+    } else {
+        Err(format!("no heading `#{fragment}` in `{}`", target.title))
+    }
+}
+
+// Site-relative path of an absolute link to the site itself
+fn own_path(url: &str, site_url: &str) -> Option<String> {
+    let path = url.strip_prefix(site_url)?;
+    match path {
+        path if path.starts_with('/') => Some(path.to_string()),
+        path if path.is_empty() || path.starts_with(['#', '?']) => Some(format!("/{path}")),
+        _ => None,
+    }
+}
+
+fn is_external(url: &str) -> bool {
+    url.starts_with("http://") || url.starts_with("https://") || url.starts_with("//")
+}
+
+fn site_url() -> String {
+    println!("cargo::rerun-if-env-changed={SITE_URL_ENV}");
+    env::var(SITE_URL_ENV).unwrap_or_else(|_| DEFAULT_SITE_URL.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::toc::Heading;
+    use chrono::NaiveDate;
+    use std::path::PathBuf;
+
+    fn entry(id: u32, slug: &str, heading_ids: &[&str]) -> Content {
+        Content {
+            source: PathBuf::from(format!("contents/posts/{slug}.md")),
+            id,
+            date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            title: slug.to_string(),
+            slug: slug.to_string(),
+            summary: None,
+            tags: Vec::new(),
+            author: None,
+            cover_image: None,
+            draft: false,
+            publish_at: None,
+            html: String::new(),
+            headings: heading_ids
+                .iter()
+                .map(|id| Heading {
+                    level: 2,
+                    id: id.to_string(),
+                    title: id.to_string(),
+                })
+                .collect(),
+            word_count: 0,
+            reading_minutes: 0,
+            description: String::new(),
+            text: String::new(),
+            related: Vec::new(),
+            links: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn resolves_entries_by_slug_and_id() {
+        let posts = [
+            entry(4, "rust-ownership", &["borrowing"]),
+            entry(12, "async", &[]),
+        ];
+        let poems = [entry(1, "mua-thu", &[])];
+        let sections: &[(&str, &[Content])] = &[("posts", &posts), ("poems", &poems)];
+        let resolve = |link: &str| resolve_link(link, &posts[1], sections);
+
+        assert_eq!(resolve("/posts/rust-ownership"), Ok(()));
+        assert_eq!(resolve("/posts/rust-ownership/"), Ok(()));
+        assert_eq!(resolve("/posts/4"), Ok(()));
+        assert_eq!(resolve("/posts/0004"), Ok(()));
+        assert_eq!(resolve("/poems/mua-thu?ref=home"), Ok(()));
+        assert!(resolve("/posts/missing").is_err());
+        assert!(resolve("/posts/5").is_err());
+        // Poems and posts have their own ids
+        assert!(resolve("/poems/4").is_err());
+        // Other routes are not checked
+        assert_eq!(resolve("/tags/rust"), Ok(()));
+    }
+
+    #[test]
+    fn resolves_heading_fragments() {
+        let posts = [
+            entry(4, "rust-ownership", &["borrowing"]),
+            entry(12, "async", &["tasks"]),
+        ];
+        let sections: &[(&str, &[Content])] = &[("posts", &posts)];
+        let resolve = |link: &str| resolve_link(link, &posts[1], sections);
+
+        assert_eq!(resolve("/posts/rust-ownership#borrowing"), Ok(()));
+        assert_eq!(resolve("/posts/0004#borrowing"), Ok(()));
+        assert!(resolve("/posts/rust-ownership#lifetimes").is_err());
+        // Same entry
+        assert_eq!(resolve("#tasks"), Ok(()));
+        assert!(resolve("#borrowing").is_err());
+    }
+}
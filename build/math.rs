@@ -0,0 +1,31 @@
+use pulldown_cmark::{CowStr, Event};
+use pulldown_latex::{Parser, RenderConfig, Storage, config::DisplayMode, push_mathml};
+
+// Typeset `$inline$` and `$$display$$` math as MathML, which browsers render natively,
+// so no KaTeX/MathJax script is shipped. The TeX source is kept as an annotation
+// Invalid TeX is still rendered (highlighted), and reported in `errors`
+pub fn render_math<'a>(event: Event<'a>, errors: &mut Vec<String>) -> Event<'a> {
+    let (tex, display_mode) = match &event {
+        Event::InlineMath(tex) => (tex, DisplayMode::Inline),
+        Event::DisplayMath(tex) => (tex, DisplayMode::Block),
+        _ => return event,
+    };
+
+    let storage = Storage::new();
+    let events: Vec<_> = Parser::new(tex, &storage).collect();
+    if let Some(Err(e)) = events.iter().find(|event| event.is_err()) {
+        // The rest of the message is an ASCII-art pointer into the source
+        let message = e.to_string();
+        let reason = message.lines().next().unwrap_or_default();
+        errors.push(format!("invalid math `{tex}`: {reason}"));
+    }
+
+    let mut mathml = String::new();
+    let config = RenderConfig {
+        display_mode,
+        ..RenderConfig::with_annotation(tex)
+    };
+    push_mathml(&mut mathml, events.into_iter(), config).unwrap();
+    // Display math sits in a paragraph, so it is inline HTML either way
+    Event::InlineHtml(CowStr::from(mathml))
+}
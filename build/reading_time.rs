@@ -0,0 +1,63 @@
+use pulldown_cmark::{Event, Tag, TagEnd};
+
+// Silent reading speeds - Vietnamese is counted in syllables, which are written as separate
+// words, so the same text yields more "words" but each one reads faster
+const ENGLISH_WORDS_PER_MINUTE: u32 = 230;
+const VIETNAMESE_SYLLABLES_PER_MINUTE: u32 = 300;
+
+// Text with at least this share of Vietnamese syllables is read at the Vietnamese speed
+const VIETNAMESE_SHARE: f32 = 0.2;
+
+// Letters that only occur in Vietnamese (base letters and tone marks)
+const VIETNAMESE_LETTERS: &str = "ăâđêôơưĂÂĐÊÔƠƯ";
+
+#[derive(Debug, Default)]
+pub struct ReadingStats {
+    pub word_count: u32,
+    pub reading_minutes: u32,
+}
+
+// Counts the words of the prose while markdown events stream by, leaving out code blocks
+#[derive(Debug, Default)]
+pub struct WordCounter {
+    in_code_block: bool,
+    words: u32,
+    vietnamese_words: u32,
+}
+
+impl WordCounter {
+    pub fn observe(&mut self, event: &Event) {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => self.in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => self.in_code_block = false,
+            Event::Text(text) if !self.in_code_block => {
+                for word in text.split_whitespace() {
+                    self.words += 1;
+                    if word.chars().any(is_vietnamese) {
+                        self.vietnamese_words += 1;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn stats(&self) -> ReadingStats {
+        let is_vietnamese =
+            self.words > 0 && self.vietnamese_words as f32 / self.words as f32 >= VIETNAMESE_SHARE;
+        let per_minute = if is_vietnamese {
+            VIETNAMESE_SYLLABLES_PER_MINUTE
+        } else {
+            ENGLISH_WORDS_PER_MINUTE
+        };
+        ReadingStats {
+            word_count: self.words,
+            reading_minutes: self.words.div_ceil(per_minute).max(1),
+        }
+    }
+}
+
+// Vietnamese letters and the precomposed tone-marked vowels (Latin Extended Additional)
+fn is_vietnamese(c: char) -> bool {
+    VIETNAMESE_LETTERS.contains(c) || ('\u{1ea0}'..='\u{1ef9}').contains(&c)
+}
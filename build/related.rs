@@ -0,0 +1,100 @@
+use crate::Content;
+use crate::tokenize::tokenize;
+use std::collections::{HashMap, HashSet};
+
+// Entries listed as related to each entry
+const RELATED_COUNT: usize = 3;
+
+// A shared tag counts as much as this much text similarity (cosine, 0 to 1)
+const TAG_WEIGHT: f32 = 0.5;
+
+// Entries less similar than this are not worth suggesting
+const MIN_SCORE: f32 = 0.05;
+
+// Fill in the related entries of a section: shared tags first, then term overlap,
+// compared as tf-idf vectors of the plain text and title
+pub fn assign_related(posts: &mut [Content]) {
+    let term_counts: Vec<HashMap<String, f32>> = posts
+        .iter()
+        .map(|post| {
+            let mut counts = HashMap::new();
+            for token in tokenize(&post.title)
+                .into_iter()
+                .chain(tokenize(&post.text))
+            {
+                *counts.entry(token).or_insert(0.0) += 1.0;
+            }
+            counts
+        })
+        .collect();
+
+    let mut document_frequency: HashMap<&str, f32> = HashMap::new();
+    for counts in &term_counts {
+        for term in counts.keys() {
+            *document_frequency.entry(term).or_insert(0.0) += 1.0;
+        }
+    }
+    let total = posts.len() as f32;
+    let vectors: Vec<HashMap<&str, f32>> = term_counts
+        .iter()
+        .map(|counts| {
+            let vector: HashMap<&str, f32> = counts
+                .iter()
+                .map(|(term, count)| {
+                    let idf = (total / document_frequency[term.as_str()]).ln();
+                    (term.as_str(), count * idf)
+                })
+                .collect();
+            normalize(vector)
+        })
+        .collect();
+
+    let related: Vec<Vec<u32>> = (0..posts.len())
+        .map(|index| {
+            let tags: HashSet<&String> = posts[index].tags.iter().collect();
+            let mut scored: Vec<(f32, u32)> = (0..posts.len())
+                .filter(|other| *other != index)
+                .map(|other| {
+                    let shared_tags = posts[other]
+                        .tags
+                        .iter()
+                        .filter(|tag| tags.contains(tag))
+                        .count();
+                    let score =
+                        shared_tags as f32 * TAG_WEIGHT + cosine(&vectors[index], &vectors[other]);
+                    (score, posts[other].id)
+                })
+                .filter(|(score, _)| *score >= MIN_SCORE)
+                .collect();
+            scored.sort_by(|a, b| b.0.total_cmp(&a.0).then(b.1.cmp(&a.1)));
+            scored
+                .into_iter()
+                .take(RELATED_COUNT)
+                .map(|(_, id)| id)
+                .collect()
+        })
+        .collect();
+
+    for (post, related) in posts.iter_mut().zip(related) {
+        post.related = related;
+    }
+}
+
+fn normalize(mut vector: HashMap<&str, f32>) -> HashMap<&str, f32> {
+    let norm = vector
+        .values()
+        .map(|weight| weight * weight)
+        .sum::<f32>()
+        .sqrt();
+    if norm > 0.0 {
+        vector.values_mut().for_each(|weight| *weight /= norm);
+    }
+    vector
+}
+
+// Both vectors are normalised, so the dot product is the cosine
+fn cosine(a: &HashMap<&str, f32>, b: &HashMap<&str, f32>) -> f32 {
+    a.iter()
+        .filter_map(|(term, weight)| Some(weight * b.get(term)?))
+        .sum()
+}
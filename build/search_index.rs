@@ -0,0 +1,54 @@
+use crate::Content;
+use crate::tokenize::tokenize;
+use std::{collections::BTreeMap, fmt::Write, fs, path::Path};
+
+// A title or tag hit counts as much as this many hits in the body
+const TITLE_WEIGHT: u16 = 8;
+const TAG_WEIGHT: u16 = 4;
+
+// Write the inverted index as `crate::shared::search` tables:
+// SEARCH_DOCS holds the plain text of every entry (for snippets), in section order,
+// SEARCH_TERMS maps each folded term, sorted, to (doc index, weighted term frequency) postings
+pub fn write_search_index(sections: &[(&str, &[Content])], dest_path: &Path) {
+    let mut docs = String::new();
+    let mut terms: BTreeMap<String, Vec<(u16, u16)>> = BTreeMap::new();
+
+    let entries = sections
+        .iter()
+        .flat_map(|(kind, posts)| posts.iter().map(move |post| (*kind, post)));
+    for (doc, (kind, post)) in entries.enumerate() {
+        let doc = u16::try_from(doc).expect("search index supports up to 65535 entries");
+        writeln!(
+            docs,
+            "    SearchDoc {{ kind: {kind}, id: {}, text: {:?} }},",
+            post.id, post.text
+        )
+        .unwrap();
+
+        let mut weights: BTreeMap<String, u16> = BTreeMap::new();
+        let tags = post.tags.join(" ");
+        let fields = [
+            (post.text.as_str(), 1),
+            (post.title.as_str(), TITLE_WEIGHT),
+            (tags.as_str(), TAG_WEIGHT),
+        ];
+        for (field, weight) in fields {
+            for token in tokenize(field) {
+                let total = weights.entry(token).or_default();
+                *total = total.saturating_add(weight);
+            }
+        }
+        for (token, weight) in weights {
+            terms.entry(token).or_default().push((doc, weight));
+        }
+    }
+
+    let mut out = format!("pub static SEARCH_DOCS: &[SearchDoc] = &[\n{docs}];\n\n");
+    out.push_str("pub static SEARCH_TERMS: &[(&str, &[(u16, u16)])] = &[\n");
+    for (term, postings) in terms {
+        writeln!(out, "    ({term:?}, &{postings:?}),").unwrap();
+    }
+    out.push_str("];\n");
+
+    fs::write(dest_path, out).unwrap();
+}
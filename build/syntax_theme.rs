@@ -0,0 +1,126 @@
+use std::{
+    env, fs,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::Path,
+    sync::OnceLock,
+};
+use syntect::{
+    highlighting::ThemeSet,
+    html::{ClassStyle, css_for_theme_with_class_style},
+    parsing::SyntaxSet,
+};
+
+// Extra `.sublime-syntax` and `.tmTheme` files, on top of the syntect defaults
+const CUSTOM_DIR: &str = "./contents/_syntaxes";
+
+// Syntect themes of the code blocks, eg: BLOG_SYNTAX_THEME_DARK=Solarized (dark)
+// Themes from CUSTOM_DIR are named after their file stem
+const LIGHT_THEME_ENV: &str = "BLOG_SYNTAX_THEME_LIGHT";
+const DARK_THEME_ENV: &str = "BLOG_SYNTAX_THEME_DARK";
+const DEFAULT_LIGHT_THEME: &str = "InspiredGitHub";
+const DEFAULT_DARK_THEME: &str = "base16-ocean.dark";
+
+// Classes emitted by `highlight` - must match the generated stylesheet
+pub const CLASS_STYLE: ClassStyle = ClassStyle::Spaced;
+
+// Write the code block stylesheet to OUT_DIR/syntax.css, and its version for cache busting
+// to OUT_DIR/syntax_css.rs
+// Colours follow `data-theme` set by the theme toggle, then the system preference
+pub fn write_syntax_css(out_dir: &Path) {
+    let mut theme_set = ThemeSet::load_defaults();
+    let custom_dir = Path::new(CUSTOM_DIR);
+    if custom_dir.is_dir() {
+        theme_set
+            .add_from_folder(custom_dir)
+            .unwrap_or_else(|e| panic!("{CUSTOM_DIR}: failed to load themes: {e}"));
+    }
+    let light = theme_css(&theme_set, LIGHT_THEME_ENV, DEFAULT_LIGHT_THEME);
+    let dark = theme_css(&theme_set, DARK_THEME_ENV, DEFAULT_DARK_THEME);
+
+    let css = format!(
+        "/* Generated by build.rs - code block colours */\n{}\n@media (prefers-color-scheme: dark) {{\n{}}}\n{}",
+        scope_css(&light, r#":root:not([data-theme="dark"])"#),
+        scope_css(&dark, ":root:not([data-theme])"),
+        scope_css(&dark, r#"[data-theme="dark"]"#),
+    );
+
+    let mut hasher = DefaultHasher::new();
+    css.hash(&mut hasher);
+    fs::write(out_dir.join("syntax.css"), &css).unwrap();
+    fs::write(
+        out_dir.join("syntax_css.rs"),
+        format!(
+            "/// Version of the generated code block stylesheet, for cache busting\npub const SYNTAX_CSS_VERSION: &str = \"{:016x}\";\n",
+            hasher.finish()
+        ),
+    )
+    .unwrap();
+}
+
+// Default syntaxes with the custom ones, loaded once for every content file
+pub fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAX_SET.get_or_init(|| {
+        let custom_dir = Path::new(CUSTOM_DIR);
+        if !custom_dir.is_dir() {
+            return SyntaxSet::load_defaults_newlines();
+        }
+        let mut builder = SyntaxSet::load_defaults_newlines().into_builder();
+        builder
+            .add_from_folder(custom_dir, true)
+            .unwrap_or_else(|e| panic!("{CUSTOM_DIR}: failed to load syntaxes: {e}"));
+        builder.build()
+    })
+}
+
+fn theme_css(theme_set: &ThemeSet, env_name: &str, default: &str) -> String {
+    println!("cargo::rerun-if-env-changed={env_name}");
+    let name = env::var(env_name).unwrap_or_else(|_| default.to_string());
+    let Some(theme) = theme_set.themes.get(&name) else {
+        let mut available: Vec<_> = theme_set.themes.keys().map(String::as_str).collect();
+        available.sort();
+        panic!(
+            "{env_name}: unknown syntax theme `{name}`, available: {}",
+            available.join(", ")
+        );
+    };
+    let mut css = css_for_theme_with_class_style(theme, CLASS_STYLE).unwrap();
+    // Lines picked out with `{3,5-7}` in the fence, when the theme has a colour for them
+    if let Some(color) = theme.settings.line_highlight {
+        css.push_str(&format!(
+            ".code-line.highlighted {{\n background-color: #{:02x}{:02x}{:02x}{:02x};\n}}\n",
+            color.r, color.g, color.b, color.a
+        ));
+    }
+    css
+}
+
+// Prefix every rule with the scope, keeping token rules inside `.code` blocks so the
+// unprefixed class names cannot style the rest of the page
+fn scope_css(css: &str, scope: &str) -> String {
+    let mut scoped = String::new();
+    for rule in css.split_inclusive('}') {
+        let Some((selectors, body)) = rule.split_once('{') else {
+            continue;
+        };
+        // Drop the comment syntect puts before the first rule
+        let selectors = match selectors.rfind("*/") {
+            Some(end) => &selectors[end + 2..],
+            None => selectors,
+        };
+        let selectors: Vec<String> = selectors
+            .split(',')
+            .map(str::trim)
+            .filter(|selector| !selector.is_empty())
+            .map(|selector| match selector {
+                // Pico gives <code> its own colours, so the block rule covers it too
+                ".code" => format!("{scope} .code, {scope} .code > code"),
+                selector => format!("{scope} .code {selector}"),
+            })
+            .collect();
+        if !selectors.is_empty() {
+            scoped.push_str(&format!("{} {{{body}\n", selectors.join(", ")));
+        }
+    }
+    scoped
+}
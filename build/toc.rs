@@ -0,0 +1,134 @@
+use crate::tokenize::fold;
+use pulldown_cmark::{CowStr, Event, Tag, TagEnd};
+use std::{collections::HashMap, env};
+
+// Set to `0`/`false` to leave out the `#` self-link after every heading
+const HEADING_ANCHORS_ENV: &str = "BLOG_HEADING_ANCHORS";
+
+// The title heading (h1) and headings below h4 are left out of the table of contents
+const TOC_LEVELS: std::ops::RangeInclusive<u8> = 2..=4;
+
+// A heading of the document, in order of appearance
+#[derive(Debug)]
+pub struct Heading {
+    pub level: u8,
+    pub id: String,
+    pub title: String,
+}
+
+// Give every heading an id - an explicit `{#id}` is kept, otherwise the slugified text is used,
+// suffixed with -2, -3.. when it repeats - and optionally a self-link anchor
+// Returns the events with the headings of the document
+pub fn add_heading_ids<'a>(events: Vec<Event<'a>>) -> (Vec<Event<'a>>, Vec<Heading>) {
+    let anchors = heading_anchors();
+    let mut output = Vec::with_capacity(events.len());
+    let mut headings = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut pending: Option<Vec<Event<'a>>> = None;
+
+    for event in events {
+        match event {
+            Event::Start(Tag::Heading { .. }) => pending = Some(vec![event]),
+            Event::End(TagEnd::Heading(level)) => {
+                let Some(mut buffered) = pending.take() else {
+                    output.push(event);
+                    continue;
+                };
+                let title = heading_text(&buffered[1..]);
+                let Event::Start(Tag::Heading { id, .. }) = &mut buffered[0] else {
+                    unreachable!("buffer starts with the heading start")
+                };
+                let base = id
+                    .as_deref()
+                    .map_or_else(|| slugify(&title), str::to_string);
+                let count = seen.entry(base.clone()).or_default();
+                *count += 1;
+                let unique = match *count {
+                    1 => base,
+                    n => format!("{base}-{n}"),
+                };
+                *id = Some(CowStr::from(unique.clone()));
+
+                output.extend(buffered);
+                if anchors {
+                    output.push(Event::InlineHtml(CowStr::from(format!(
+                        r##"<a class="heading-anchor" href="#{unique}" aria-label="Link to this section">#</a>"##
+                    ))));
+                }
+                output.push(event);
+                headings.push(Heading {
+                    level: level as u8,
+                    id: unique,
+                    title,
+                });
+            }
+            event => match pending.as_mut() {
+                Some(buffered) => buffered.push(event),
+                None => output.push(event),
+            },
+        }
+    }
+
+    (output, headings)
+}
+
+// Nested `crate::shared::content::TocEntry` literal of the table of contents
+pub fn toc_literal(headings: &[Heading]) -> String {
+    let headings: Vec<&Heading> = headings
+        .iter()
+        .filter(|heading| TOC_LEVELS.contains(&heading.level))
+        .collect();
+    toc_entries(&headings)
+}
+
+// Headings are nested under the closest preceding heading of a higher level
+fn toc_entries(headings: &[&Heading]) -> String {
+    let mut entries = Vec::new();
+    let mut index = 0;
+    while index < headings.len() {
+        let heading = headings[index];
+        let children_end = headings[index + 1..]
+            .iter()
+            .position(|child| child.level <= heading.level)
+            .map_or(headings.len(), |offset| index + 1 + offset);
+        entries.push(format!(
+            "TocEntry {{ level: {}, id: {:?}, title: {:?}, children: {} }}",
+            heading.level,
+            heading.id,
+            heading.title,
+            toc_entries(&headings[index + 1..children_end]),
+        ));
+        index = children_end;
+    }
+    format!("&[{}]", entries.join(", "))
+}
+
+// Plain text of the heading content
+fn heading_text(events: &[Event]) -> String {
+    let mut text = String::new();
+    for event in events {
+        if let Event::Text(t) | Event::Code(t) = event {
+            text.push_str(t);
+        }
+    }
+    text.trim().to_string()
+}
+
+// Lowercase ASCII words joined by hyphens - Vietnamese accents are folded
+fn slugify(text: &str) -> String {
+    let slug = fold(text)
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        "section".to_string()
+    } else {
+        slug
+    }
+}
+
+fn heading_anchors() -> bool {
+    println!("cargo::rerun-if-env-changed={HEADING_ANCHORS_ENV}");
+    env::var(HEADING_ANCHORS_ENV).map_or(true, |value| !matches!(value.as_str(), "0" | "false"))
+}
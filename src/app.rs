@@ -4,6 +4,7 @@ pub mod helpers;
 pub mod pages;
 
 // Import necessary crates and modules
+use crate::shared::site::SYNTAX_CSS_VERSION;
 use components::{Footer, Nav};
 use leptos::prelude::*;
 use leptos_meta::{MetaTags, Stylesheet, Title, provide_meta_context};
@@ -23,9 +24,11 @@ pub fn shell(options: LeptosOptions) -> impl IntoView {
             <head>
                 <meta charset="utf-8" />
                 <meta name="viewport" content="width=device-width, initial-scale=1" />
-                <meta name="description" content="Willian's personal website" />
                 <meta name="color-scheme" content="light dark" />
                 <link rel="icon" href="/favico.svg" />
+                <link rel="alternate" type="application/rss+xml" title="Willian Nguyen (RSS)" href="/rss.xml" />
+                <link rel="alternate" type="application/atom+xml" title="Willian Nguyen (Atom)" href="/atom.xml" />
+                <link rel="alternate" type="application/feed+json" title="Willian Nguyen (JSON Feed)" href="/feed.json" />
                 <AutoReload options=options.clone() />
                 <HydrationScripts options />
                 <MetaTags />
@@ -46,6 +49,8 @@ pub fn App() -> impl IntoView {
         <Stylesheet id="leptos" href="/style/pico.min.css" />
         <Stylesheet id="nav-styles" href="/style/nav-styles.css" />
         <Stylesheet id="footer-styles" href="/style/footer-styles.css" />
+        <Stylesheet id="content-styles" href="/style/content-styles.css" />
+        <Stylesheet id="syntax-styles" href=format!("/style/syntax.css?v={SYNTAX_CSS_VERSION}") />
 
         <Title text="Welcome to Willian's blog" />
 
@@ -61,10 +66,17 @@ pub fn App() -> impl IntoView {
                     <Route path=StaticSegment("") view=HomePage />
                     // Posts section
                     <Route path=StaticSegment("/posts") view=PostListPage />
-                    <Route path=path!("/posts/:id") view=SinglePostPage />
+                    <Route path=path!("/posts/:slug") view=SinglePostPage />
                     // Poems section
                     <Route path=StaticSegment("/poems") view=PoemListPage />
-                    <Route path=path!("/poems/:id") view=SinglePoemPage />
+                    <Route path=path!("/poems/:slug") view=SinglePoemPage />
+                    // Tags section
+                    <Route path=StaticSegment("/tags") view=TagIndexPage />
+                    <Route path=path!("/tags/:tag") view=TagPage />
+                    // Archive
+                    <Route path=StaticSegment("/archive") view=ArchivePage />
+                    // Search
+                    <Route path=StaticSegment("/search") view=SearchPage />
                 </Routes>
             </main>
 
@@ -1,16 +1,32 @@
 mod content_list;
+mod content_nav;
+mod copy_code;
 mod fast_a;
 mod footer;
 mod icons;
+mod json_ld;
 mod nav;
+mod paginated_list;
+mod search_box;
+mod seo_meta;
 mod subscribe_form;
+mod table_of_contents;
+mod tag_chips;
 mod theme_toggle;
 
 // Re-export components for easier access
 pub use content_list::ContentList;
+pub use content_nav::ContentNav;
+pub use copy_code::CopyCodeButtons;
 pub use fast_a::FastA;
 pub use footer::Footer;
 pub use icons::*;
+pub use json_ld::{JsonLd, entry_json_ld, website_json_ld};
 pub use nav::Nav;
+pub use paginated_list::PaginatedContentList;
+pub use search_box::SearchBox;
+pub use seo_meta::SeoMeta;
 pub use subscribe_form::SubscribeForm;
+pub use table_of_contents::TableOfContents;
+pub use tag_chips::TagChips;
 pub use theme_toggle::ThemeToggle;
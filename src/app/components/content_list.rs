@@ -1,30 +1,41 @@
-use super::fast_a::FastA;
-use crate::app::helpers::ContentMetadata;
+use super::{fast_a::FastA, tag_chips::TagChips};
+use crate::shared::content::ContentEntry;
 use leptos::prelude::*;
 
 #[component]
-pub fn ContentList(items: Vec<ContentMetadata>, route_prefix: &'static str) -> impl IntoView {
+pub fn ContentList(items: Vec<&'static ContentEntry>) -> impl IntoView {
     view! {
         <table>
             <thead>
                 <tr>
                     <th scope="col">Title</th>
                     <th scope="col">Date</th>
+                    <th scope="col">Words</th>
+                    <th scope="col">Read</th>
+                    <th scope="col">Tags</th>
                 </tr>
             </thead>
             <tbody>
                 {items
                     .into_iter()
                     .map(|item| {
-                        let path = format!("{}/{}", route_prefix, item.id);
                         view! {
                             <tr>
                                 <th scope="row">
-                                    <FastA href=path.clone() class="contrast">
-                                        {item.title.clone()}
+                                    <FastA href=item.path() class="contrast">
+                                        {item.title}
                                     </FastA>
                                 </th>
-                                <th scope="row">{item.date.clone()}</th>
+                                <th scope="row">{item.display_date()}</th>
+                                <td>
+                                    <small>{item.word_count}</small>
+                                </td>
+                                <td>
+                                    <small>{format!("{} min", item.reading_minutes)}</small>
+                                </td>
+                                <td>
+                                    <TagChips tags=item.tags />
+                                </td>
                             </tr>
                         }
                     })
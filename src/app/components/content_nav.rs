@@ -0,0 +1,65 @@
+use super::fast_a::FastA;
+use crate::shared::content::ContentEntry;
+use leptos::prelude::*;
+
+/// Related entries and links to the previous and next entries
+/// Type-erased, as it is nested in the deep view of the content page
+#[component]
+pub fn ContentNav(entry: &'static ContentEntry) -> AnyView {
+    let (older, newer) = entry.neighbours();
+    let related = entry.related_entries();
+
+    view! {
+        <footer class="content-nav">
+            {(!related.is_empty())
+                .then(|| {
+                    view! {
+                        <section class="related">
+                            <h2>"Related"</h2>
+                            <ul>
+                                {related
+                                    .into_iter()
+                                    .map(|item| {
+                                        view! {
+                                            <li>
+                                                <FastA href=item.path()>{item.title}</FastA>
+                                                " "
+                                                <small>{item.display_date()}</small>
+                                            </li>
+                                        }
+                                    })
+                                    .collect::<Vec<_>>()}
+                            </ul>
+                        </section>
+                    }
+                })}
+            <nav class="prev-next" aria-label="Previous and next">
+                <div>
+                    {older
+                        .map(|item| {
+                            view! {
+                                <small>"← Previous"</small>
+                                <br />
+                                <FastA href=item.path() class="contrast">
+                                    {item.title}
+                                </FastA>
+                            }
+                        })}
+                </div>
+                <div class="next">
+                    {newer
+                        .map(|item| {
+                            view! {
+                                <small>"Next →"</small>
+                                <br />
+                                <FastA href=item.path() class="contrast">
+                                    {item.title}
+                                </FastA>
+                            }
+                        })}
+                </div>
+            </nav>
+        </footer>
+    }
+    .into_any()
+}
@@ -0,0 +1,79 @@
+use leptos::{html::Div, prelude::*};
+
+#[cfg(feature = "hydrate")]
+use wasm_bindgen::{JsCast, closure::Closure};
+#[cfg(feature = "hydrate")]
+use wasm_bindgen_futures::{JsFuture, spawn_local};
+#[cfg(feature = "hydrate")]
+use web_sys::{Element, window};
+
+// How long the button reads "Copied" before going back
+#[cfg(feature = "hydrate")]
+const COPIED_RESET: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Adds a copy-to-clipboard button to every code block of the content once it is hydrated
+/// The content is HTML generated by build.rs, so the buttons are attached to its DOM
+#[component]
+pub fn CopyCodeButtons(content: NodeRef<Div>) -> impl IntoView {
+    #[cfg(feature = "hydrate")]
+    Effect::new(move |_| {
+        let Some(content) = content.get() else {
+            return;
+        };
+        let Ok(blocks) = content.query_selector_all("pre") else {
+            return;
+        };
+        for index in 0..blocks.length() {
+            if let Some(pre) = blocks.item(index).and_then(|node| node.dyn_into().ok()) {
+                attach_copy_button(&pre);
+            }
+        }
+    });
+
+    #[cfg(not(feature = "hydrate"))]
+    let _ = content;
+}
+
+#[cfg(feature = "hydrate")]
+fn attach_copy_button(pre: &Element) {
+    // The effect can run again over the same content
+    if let Ok(Some(_)) = pre.query_selector(".copy-code") {
+        return;
+    }
+    let Some(document) = pre.owner_document() else {
+        return;
+    };
+    let Ok(button) = document.create_element("button") else {
+        return;
+    };
+    button.set_class_name("copy-code");
+    let _ = button.set_attribute("type", "button");
+    let _ = button.set_attribute("aria-label", "Copy code to clipboard");
+    button.set_text_content(Some("Copy"));
+
+    // Copy the <code> only, so the button label and line numbers are left out
+    let code = match pre.query_selector("code") {
+        Ok(Some(code)) => code,
+        _ => pre.clone(),
+    };
+    let label = button.clone();
+    let on_click = Closure::<dyn Fn()>::new(move || {
+        let text = code.text_content().unwrap_or_default();
+        let label = label.clone();
+        spawn_local(async move {
+            let Some(window) = window() else {
+                return;
+            };
+            let copied = JsFuture::from(window.navigator().clipboard().write_text(&text))
+                .await
+                .is_ok();
+            label.set_text_content(Some(if copied { "Copied" } else { "Copy failed" }));
+            set_timeout(move || label.set_text_content(Some("Copy")), COPIED_RESET);
+        });
+    });
+    let _ = button.add_event_listener_with_callback("click", on_click.as_ref().unchecked_ref());
+    // The listener lives as long as the button
+    on_click.forget();
+
+    let _ = pre.prepend_with_node_1(&button);
+}
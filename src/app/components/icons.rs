@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 use lucide_leptos::{
-    Boxes, File, FileHeart, Github, House, Linkedin, Mail, Menu, Moon, NotebookPen, Sun, X,
+    Archive, Boxes, File, FileHeart, Github, House, Linkedin, Mail, Menu, Moon, NotebookPen, Sun, X,
 };
 
 /// Home icon
@@ -27,6 +27,14 @@ pub fn PoemIcon() -> impl IntoView {
     }
 }
 
+/// Archive icon
+#[component]
+pub fn ArchiveIcon() -> impl IntoView {
+    view! {
+        <Archive size=27 />
+    }
+}
+
 /// Contact/Mail icon
 #[component]
 pub fn ContactIcon() -> impl IntoView {
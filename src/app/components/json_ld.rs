@@ -0,0 +1,87 @@
+use crate::shared::content::{ContentEntry, ContentKind};
+use crate::shared::site::{
+    AUTHOR_PROFILES, SITE_AUTHOR, SITE_DESCRIPTION, SITE_TITLE, SITE_URL, absolute_media_url,
+    absolute_url,
+};
+use leptos::prelude::*;
+use serde_json::{Value, json};
+
+/// schema.org structured data as a JSON-LD script block
+/// Type-erased, as pages nest it with their other head tags
+#[component]
+pub fn JsonLd(data: Value) -> AnyView {
+    // `</` would close the script element early, JSON allows escaping the slash
+    let json = data.to_string().replace("</", "<\\/");
+    view! { <script type="application/ld+json" inner_html=json></script> }.into_any()
+}
+
+/// `BlogPosting` for a post, `CreativeWork` for a poem
+pub fn entry_json_ld(entry: &ContentEntry) -> Value {
+    let url = absolute_url(&entry.path());
+    let published = entry.published_time().to_rfc3339();
+    let (schema_type, genre, language) = match entry.kind {
+        ContentKind::Post => ("BlogPosting", None, "en"),
+        ContentKind::Poem => ("CreativeWork", Some("Poetry"), "vi"),
+    };
+
+    let mut data = json!({
+        "@context": "https://schema.org",
+        "@type": schema_type,
+        "headline": entry.title,
+        "name": entry.title,
+        "description": entry.description,
+        "url": url,
+        "mainEntityOfPage": url,
+        "datePublished": published,
+        "dateModified": published,
+        "inLanguage": language,
+        "wordCount": entry.word_count,
+        "author": person(entry.author.unwrap_or(SITE_AUTHOR)),
+    });
+    if let Some(genre) = genre {
+        data["genre"] = json!(genre);
+    }
+    if !entry.tags.is_empty() {
+        data["keywords"] = json!(entry.tags.join(", "));
+    }
+    if let Some(image) = entry.cover_image {
+        data["image"] = json!(absolute_media_url(image));
+    }
+    data
+}
+
+/// `Person` and `WebSite` of the home page, with the site search as a `SearchAction`
+pub fn website_json_ld() -> Value {
+    json!({
+        "@context": "https://schema.org",
+        "@graph": [
+            person(SITE_AUTHOR),
+            {
+                "@type": "WebSite",
+                "name": SITE_TITLE,
+                "description": SITE_DESCRIPTION,
+                "url": SITE_URL,
+                "author": { "@type": "Person", "name": SITE_AUTHOR },
+                "potentialAction": {
+                    "@type": "SearchAction",
+                    "target": format!("{}?q={{search_term_string}}", absolute_url("/search")),
+                    "query-input": "required name=search_term_string",
+                },
+            },
+        ],
+    })
+}
+
+// The site author gets the site URL and profiles, guest authors only a name
+fn person(name: &str) -> Value {
+    if name == SITE_AUTHOR {
+        json!({
+            "@type": "Person",
+            "name": name,
+            "url": SITE_URL,
+            "sameAs": AUTHOR_PROFILES,
+        })
+    } else {
+        json!({ "@type": "Person", "name": name })
+    }
+}
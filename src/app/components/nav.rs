@@ -1,4 +1,4 @@
-use super::{fast_a::FastA, icons::*, theme_toggle::ThemeToggle};
+use super::{fast_a::FastA, icons::*, search_box::SearchBox, theme_toggle::ThemeToggle};
 use leptos::{ev::MouseEvent, prelude::*};
 use std::collections::HashMap;
 
@@ -142,6 +142,15 @@ pub fn Nav() -> impl IntoView {
                         </FastA>
                     </li>
 
+                    <li class="nav-item">
+                        <FastA href="/archive" class="nav-link">
+                            <div class="nav-icon">
+                                <ArchiveIcon />
+                            </div>
+                            <span>"Archive"</span>
+                        </FastA>
+                    </li>
+
             //         <li class="nav-item">
             //             <FastA href="/resume" class="nav-link">
             //                 <div class="nav-icon">
@@ -152,6 +161,9 @@ pub fn Nav() -> impl IntoView {
             //         </li>
                 </ul>
 
+                // Search
+                <SearchBox class="nav-search" />
+
                 // Theme Toggle
                 <div class="nav-theme">
                     <ThemeToggle />
@@ -247,6 +259,17 @@ pub fn Nav() -> impl IntoView {
                             </div>
                         </li>
 
+                        <li class="mobile-nav-item">
+                            <div on:click=close_mobile_menu>
+                                <FastA href="/archive" class="mobile-nav-link">
+                                    <div class="mobile-nav-icon">
+                                        <ArchiveIcon />
+                                    </div>
+                                    <span>"Archive"</span>
+                                </FastA>
+                            </div>
+                        </li>
+
                     //     <li class="mobile-nav-item">
                     //         <div on:click=close_mobile_menu>
                     //             <FastA href="/resume" class="mobile-nav-link">
@@ -259,6 +282,9 @@ pub fn Nav() -> impl IntoView {
                     //     </li>
                     </ul>
 
+                    // Search in Mobile Menu
+                    <SearchBox class="mobile-nav-search" />
+
                     // Theme Toggle in Mobile Menu
                     <div class="mobile-nav-theme">
                         <ThemeToggle />
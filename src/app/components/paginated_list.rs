@@ -0,0 +1,124 @@
+use super::content_list::ContentList;
+use crate::shared::content::ContentEntry;
+use crate::shared::search::fold;
+use crate::shared::site::LIST_PAGE_SIZE;
+use leptos::prelude::*;
+use leptos_meta::Link;
+use leptos_router::hooks::use_query_map;
+
+/// Order of a content list, from the `sort` query parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ListSort {
+    /// Newest first - the default
+    Date,
+    /// Alphabetical, accents ignored
+    Title,
+}
+
+impl ListSort {
+    fn from_query(value: Option<String>) -> Self {
+        match value.as_deref() {
+            Some("title") => ListSort::Title,
+            _ => ListSort::Date,
+        }
+    }
+
+    fn query_value(&self) -> &'static str {
+        match self {
+            ListSort::Date => "date",
+            ListSort::Title => "title",
+        }
+    }
+}
+
+/// Content list split in pages with `?page=` and ordered with `?sort=date|title`
+#[component]
+pub fn PaginatedContentList(
+    items: Vec<&'static ContentEntry>,
+    /// Path of the list page the pagination links point to eg: /posts
+    base_path: &'static str,
+    #[prop(default = LIST_PAGE_SIZE)] page_size: usize,
+) -> impl IntoView {
+    let query = use_query_map();
+    let page_size = page_size.max(1);
+
+    move || {
+        let (page, sort) = query.with(|query| {
+            let page = query
+                .get("page")
+                .and_then(|page| page.parse::<usize>().ok())
+                .unwrap_or(1);
+            (page, ListSort::from_query(query.get("sort")))
+        });
+
+        let mut sorted = items.clone();
+        if sort == ListSort::Title {
+            sorted.sort_by_cached_key(|item| fold(item.title));
+        }
+
+        let page_count = sorted.len().div_ceil(page_size).max(1);
+        let page = page.clamp(1, page_count);
+        let page_items: Vec<_> = sorted
+            .into_iter()
+            .skip((page - 1) * page_size)
+            .take(page_size)
+            .collect();
+
+        let href = move |page: usize, sort: ListSort| list_href(base_path, page, sort);
+        let prev = (page > 1).then(|| href(page - 1, sort));
+        let next = (page < page_count).then(|| href(page + 1, sort));
+
+        view! {
+            {prev.clone().map(|href| view! { <Link rel="prev" href=href /> })}
+            {next.clone().map(|href| view! { <Link rel="next" href=href /> })}
+            <nav class="list-sort" aria-label="Sort">
+                <small>"Sort by: "</small>
+                {[ListSort::Date, ListSort::Title]
+                    .into_iter()
+                    .map(|option| {
+                        let label = match option {
+                            ListSort::Date => "Date",
+                            ListSort::Title => "Title",
+                        };
+                        view! {
+                            <a
+                                href=href(1, option)
+                                class="secondary"
+                                aria-current=(option == sort).then_some("true")
+                            >
+                                {label}
+                            </a>
+                        }
+                    })
+                    .collect::<Vec<_>>()}
+            </nav>
+            <ContentList items=page_items />
+            {(page_count > 1)
+                .then(|| {
+                    view! {
+                        <nav class="pagination" aria-label="Pagination">
+                            {prev.map(|href| view! { <a href=href rel="prev">"← Previous"</a> })}
+                            <small>{format!("Page {page} of {page_count}")}</small>
+                            {next.map(|href| view! { <a href=href rel="next">"Next →"</a> })}
+                        </nav>
+                    }
+                })}
+        }
+    }
+}
+
+// Query parameters at their default (first page, by date) are left out
+fn list_href(base_path: &str, page: usize, sort: ListSort) -> String {
+    let mut params = Vec::new();
+    if page > 1 {
+        params.push(format!("page={page}"));
+    }
+    if sort != ListSort::Date {
+        params.push(format!("sort={}", sort.query_value()));
+    }
+    if params.is_empty() {
+        base_path.to_string()
+    } else {
+        format!("{base_path}?{}", params.join("&"))
+    }
+}
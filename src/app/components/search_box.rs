@@ -0,0 +1,22 @@
+use leptos::prelude::*;
+use leptos_router::components::Form;
+
+#[component]
+pub fn SearchBox(
+    #[prop(optional, into)] value: MaybeProp<String>,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    view! {
+        <div class=class.unwrap_or_else(|| "search-box".to_string())>
+            <Form method="GET" action="/search">
+                <input
+                    type="search"
+                    name="q"
+                    placeholder="Search posts and poems"
+                    aria-label="Search"
+                    prop:value=move || value.get().unwrap_or_default()
+                />
+            </Form>
+        </div>
+    }
+}
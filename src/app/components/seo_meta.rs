@@ -0,0 +1,51 @@
+use crate::shared::site::{SITE_TITLE, absolute_media_url, absolute_url};
+use chrono::{DateTime, Utc};
+use leptos::prelude::*;
+use leptos_meta::{Link, Meta};
+
+/// Description, canonical URL, Open Graph and Twitter card tags of a page
+/// Pages with a `published` time are described as articles
+/// Type-erased, as pages nest it with their other head tags
+#[component]
+pub fn SeoMeta(
+    #[prop(into)] title: String,
+    #[prop(into)] description: String,
+    /// Site-relative path of the page eg: /posts/4
+    #[prop(into)]
+    path: String,
+    #[prop(optional)] published: Option<DateTime<Utc>>,
+    #[prop(default = None)] image: Option<&'static str>,
+) -> AnyView {
+    let url = absolute_url(&path);
+    let image = image.map(absolute_media_url);
+    let card = if image.is_some() {
+        "summary_large_image"
+    } else {
+        "summary"
+    };
+    let og_type = if published.is_some() {
+        "article"
+    } else {
+        "website"
+    };
+
+    view! {
+        <Meta name="description" content=description.clone() />
+        <Link rel="canonical" href=url.clone() />
+        <Meta property="og:site_name" content=SITE_TITLE />
+        <Meta property="og:title" content=title.clone() />
+        <Meta property="og:description" content=description.clone() />
+        <Meta property="og:type" content=og_type />
+        <Meta property="og:url" content=url />
+        {published
+            .map(|published| {
+                view! { <Meta property="article:published_time" content=published.to_rfc3339() /> }
+            })}
+        {image.clone().map(|image| view! { <Meta property="og:image" content=image /> })}
+        <Meta name="twitter:card" content=card />
+        <Meta name="twitter:title" content=title />
+        <Meta name="twitter:description" content=description />
+        {image.map(|image| view! { <Meta name="twitter:image" content=image /> })}
+    }
+    .into_any()
+}
@@ -0,0 +1,34 @@
+use crate::shared::content::TocEntry;
+use leptos::prelude::*;
+
+#[component]
+pub fn TableOfContents(entries: &'static [TocEntry]) -> impl IntoView {
+    view! {
+        <aside class="toc">
+            <nav aria-label="Table of contents">
+                <strong>"On this page"</strong>
+                {toc_list(entries)}
+            </nav>
+        </aside>
+    }
+}
+
+// Nested lists are built recursively, so they are type-erased
+fn toc_list(entries: &'static [TocEntry]) -> AnyView {
+    view! {
+        <ul>
+            {entries
+                .iter()
+                .map(|entry| {
+                    view! {
+                        <li>
+                            <a href=format!("#{}", entry.id)>{entry.title}</a>
+                            {(!entry.children.is_empty()).then(|| toc_list(entry.children))}
+                        </li>
+                    }
+                })
+                .collect::<Vec<_>>()}
+        </ul>
+    }
+    .into_any()
+}
@@ -0,0 +1,22 @@
+use super::fast_a::FastA;
+use leptos::prelude::*;
+
+#[component]
+pub fn TagChips(tags: &'static [&'static str]) -> impl IntoView {
+    view! {
+        <ul class="tag-chips">
+            {tags
+                .iter()
+                .map(|tag| {
+                    view! {
+                        <li>
+                            <FastA href=format!("/tags/{tag}") class="tag-chip">
+                                {format!("#{tag}")}
+                            </FastA>
+                        </li>
+                    }
+                })
+                .collect::<Vec<_>>()}
+        </ul>
+    }
+}
@@ -1,70 +1,94 @@
-use leptos::prelude::*;
+use crate::app::components::{
+    ContentNav, CopyCodeButtons, JsonLd, SeoMeta, TableOfContents, TagChips, entry_json_ld,
+};
+use crate::shared::content::{ContentEntry, ContentKind};
+use leptos::{html::Div, prelude::*};
 use leptos_meta::Title;
-use leptos_router::params::ParamsMap;
+use leptos_router::{NavigateOptions, components::Redirect, params::ParamsMap};
 
-#[derive(Debug, Clone)]
-pub struct ContentMetadata {
-    pub id: String,
-    pub date: String,
-    pub title: String,
-}
-
-pub fn get_content_metadata_list(data: &[(&str, &str, &str, &str)]) -> Vec<ContentMetadata> {
-    data.iter()
-        .map(|&(id, date, title, _content)| ContentMetadata {
-            id: id.to_string(),
-            date: date.to_string(),
-            title: title.to_string(),
-        })
-        .collect()
-}
+// Articles get a table of contents sidebar from this reading time, given enough headings
+const TOC_MIN_READING_MINUTES: u32 = 3;
+const TOC_MIN_HEADINGS: usize = 2;
 
-pub fn get_content(
-    data: &[(&str, &str, &str, &str)],
-    path: &str,
-) -> Option<(ContentMetadata, String)> {
-    data.iter()
-        .filter_map(|(id, date, title, content)| {
-            if *id == path {
-                Some((
-                    ContentMetadata {
-                        id: id.to_string(),
-                        date: date.to_string(),
-                        title: title.to_string(),
-                    },
-                    content.to_string(),
-                ))
-            } else {
-                None
-            }
-        })
-        .next()
+// Drafts and entries scheduled for later are left out of lists
+pub fn get_content_metadata_list(data: &'static [ContentEntry]) -> Vec<&'static ContentEntry> {
+    data.iter().filter(|entry| entry.is_listed()).collect()
 }
 
 // NOTE: This component is rendered via a Leptos router route, not an HTTP API route.
 // All post data is accessed locally, NOT  fetched from a backend API.
 // If you add an Axum backend in the future, you can switch to fetching data via HTTP.
-pub fn render_content_page(data: &[(&str, &str, &str, &str)], params: &Memo<ParamsMap>) -> AnyView {
-    let id = params.with(|p| p.get("id").unwrap_or_default());
-    match get_content(data, &id) {
-        Some((meta, content)) => view! {
-            <Title text=meta.title.clone() />
-            <ContentPage metadata=meta content=content />
+// Old id URLs (eg: /posts/0004) are answered with a 301 by the server, in-app navigation to
+// them is redirected here, replacing the history entry like the 301 does
+pub fn render_content_page(kind: ContentKind, params: &Memo<ParamsMap>) -> AnyView {
+    let params = *params;
+    // The router keeps this view when only the slug changes (eg: following a "Next" link),
+    // so the entry is looked up again on every change of the params
+    (move || {
+        let slug = params.with(|p| p.get("slug").unwrap_or_default());
+        if let Some(entry) = kind.entry_by_id(&slug) {
+            let options = NavigateOptions {
+                replace: true,
+                ..Default::default()
+            };
+            return view! { <Redirect path=entry.path() options=options /> }.into_any();
         }
-        .into_any(),
-        None => view! { <p>"Not found."</p> }.into_any(),
-    }
+        // Scheduled entries are not found until their publication time
+        match kind
+            .entry_by_slug(&slug)
+            .filter(|entry| entry.is_published())
+        {
+            Some(entry) => view! {
+                <Title text=entry.title />
+                <SeoMeta
+                    title=entry.title
+                    description=entry.description
+                    path=entry.path()
+                    published=entry.published_time()
+                    image=entry.cover_image
+                />
+                <JsonLd data=entry_json_ld(entry) />
+                <ContentPage entry=entry />
+            }
+            .into_any(),
+            None => view! { <p>"Not found."</p> }.into_any(),
+        }
+    })
+    .into_any()
 }
 
 #[component]
-fn ContentPage(metadata: ContentMetadata, content: String) -> impl IntoView {
+fn ContentPage(entry: &'static ContentEntry) -> AnyView {
+    let show_toc =
+        entry.reading_minutes >= TOC_MIN_READING_MINUTES && entry.toc.len() >= TOC_MIN_HEADINGS;
+    let content = NodeRef::<Div>::new();
+
     view! {
-        <article>
-            <small>"Date: " {metadata.date.clone()}</small>
-            <br />
-            <br />
-            <br />
-            <div inner_html=content></div>
-        </article>
+        <div class=if show_toc { "content-layout with-toc" } else { "content-layout" }>
+            <article>
+                {entry
+                    .draft
+                    .then(|| {
+                        view! {
+                            <p class="draft-banner" role="status">
+                                <strong>"DRAFT"</strong>
+                                " - only visible in dev builds, not listed anywhere"
+                            </p>
+                        }
+                    })}
+                <small>
+                    "Date: " {entry.display_date()} " · " {entry.reading_time()} " · "
+                    {format!("{} words", entry.word_count)}
+                </small>
+                <TagChips tags=entry.tags />
+                <br />
+                <br />
+                <div node_ref=content inner_html=entry.html></div>
+                <CopyCodeButtons content=content />
+                <ContentNav entry=entry />
+            </article>
+            {show_toc.then(|| view! { <TableOfContents entries=entry.toc /> })}
+        </div>
     }
+    .into_any()
 }
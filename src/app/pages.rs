@@ -1,8 +1,14 @@
+mod archivepage;
 mod homepage;
 mod poempage;
 mod postpage;
+mod searchpage;
+mod tagpage;
 
 // Re-export pages for easier access
+pub use archivepage::ArchivePage;
 pub use homepage::HomePage;
 pub use poempage::{PoemListPage, SinglePoemPage};
 pub use postpage::{PostListPage, SinglePostPage};
+pub use searchpage::SearchPage;
+pub use tagpage::{TagIndexPage, TagPage};
@@ -0,0 +1,63 @@
+use crate::app::components::{FastA, SeoMeta};
+use crate::shared::content::{ArchiveMonth, ContentKind, archive};
+use chrono::Month;
+use leptos::prelude::*;
+use leptos_meta::Title;
+
+#[component]
+pub fn ArchivePage() -> impl IntoView {
+    view! {
+        <Title text="Willian's blog archive" />
+        <SeoMeta
+            title="Willian's blog archive"
+            description="All posts and poems by Willian Nguyen by year and month"
+            path="/archive"
+        />
+        <h1>"Archive"</h1>
+        <div class="archive">
+            {archive()
+                .into_iter()
+                .map(|year| {
+                    view! {
+                        <section>
+                            <h2 id=year.year.to_string()>
+                                {year.year} <small>{format!(" ({})", year.count())}</small>
+                            </h2>
+                            {year
+                                .months
+                                .into_iter()
+                                .map(|ArchiveMonth { month, entries }| {
+                                    let name = Month::try_from(month as u8)
+                                        .map(|month| month.name())
+                                        .unwrap_or_default();
+                                    view! {
+                                        <h3>{name} <small>{format!(" ({})", entries.len())}</small></h3>
+                                        <ul>
+                                            {entries
+                                                .into_iter()
+                                                .map(|entry| {
+                                                    let section = match entry.kind {
+                                                        ContentKind::Post => "Blog post",
+                                                        ContentKind::Poem => "Poem",
+                                                    };
+                                                    view! {
+                                                        <li>
+                                                            <FastA href=entry.path()>{entry.title}</FastA>
+                                                            <small>
+                                                                {format!(" - {section}, {}", entry.display_date())}
+                                                            </small>
+                                                        </li>
+                                                    }
+                                                })
+                                                .collect::<Vec<_>>()}
+                                        </ul>
+                                    }
+                                })
+                                .collect::<Vec<_>>()}
+                        </section>
+                    }
+                })
+                .collect::<Vec<_>>()}
+        </div>
+    }
+}
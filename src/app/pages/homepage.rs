@@ -1,3 +1,5 @@
+use crate::app::components::{JsonLd, SeoMeta, website_json_ld};
+use crate::shared::site::SITE_DESCRIPTION;
 use leptos::prelude::*;
 use leptos_meta::Title;
 
@@ -5,6 +7,8 @@ use leptos_meta::Title;
 pub fn HomePage() -> impl IntoView {
     view! {
         <Title text="Willian Nguyen - Home" />
+        <SeoMeta title="Willian Nguyen - Home" description=SITE_DESCRIPTION path="/" />
+        <JsonLd data=website_json_ld() />
         <h1>"Hello there, 👋"</h1>
         <p>"I am a curious learner with a genuine love for new technologies."</p>
         <p>"Anything that I learn and find interesting, I will write about it on my blog."</p>
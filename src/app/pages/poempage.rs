@@ -1,7 +1,6 @@
-include!(concat!(env!("OUT_DIR"), "/poems_data.rs"));
-
-use crate::app::components::ContentList;
+use crate::app::components::{PaginatedContentList, SeoMeta};
 use crate::app::helpers::{get_content_metadata_list, render_content_page};
+use crate::shared::content::{ContentKind, POEMS};
 use leptos::prelude::*;
 use leptos_meta::Title;
 use leptos_router::hooks::use_params_map;
@@ -10,6 +9,11 @@ use leptos_router::hooks::use_params_map;
 pub fn PoemListPage() -> impl IntoView {
     view! {
         <Title text="Willian's poem posts" />
+        <SeoMeta
+            title="Willian's poem posts"
+            description="Vietnamese poems by Willian Nguyen - witty, sarcastic and sometimes emotional"
+            path="/poems"
+        />
         <p> I am not purely technical. I do poetry here and there just to kill time. </p>
         <p> My poems are in Vietnamese, my mother-tounge. Most of them are just witty and sarcastic. They can be emotional sometimes. </p>
         <h1>"Poem Posts"</h1>
@@ -20,11 +24,11 @@ pub fn PoemListPage() -> impl IntoView {
 #[component]
 pub fn PoemList() -> impl IntoView {
     let posts = get_content_metadata_list(POEMS);
-    view! { <ContentList items=posts route_prefix="/poems" /> }
+    view! { <PaginatedContentList items=posts base_path="/poems" /> }
 }
 
 #[component]
 pub fn SinglePoemPage() -> impl IntoView {
     let params = use_params_map();
-    render_content_page(POEMS, &params)
+    render_content_page(ContentKind::Poem, &params)
 }
@@ -1,7 +1,6 @@
-include!(concat!(env!("OUT_DIR"), "/posts_data.rs"));
-
-use crate::app::components::ContentList;
+use crate::app::components::{PaginatedContentList, SeoMeta};
 use crate::app::helpers::{get_content_metadata_list, render_content_page};
+use crate::shared::content::{ContentKind, POSTS};
 use leptos::prelude::*;
 use leptos_meta::Title;
 use leptos_router::hooks::use_params_map;
@@ -10,6 +9,11 @@ use leptos_router::hooks::use_params_map;
 pub fn PostListPage() -> impl IntoView {
     view! {
         <Title text="Willian's blog posts" />
+        <SeoMeta
+            title="Willian's blog posts"
+            description="Blog posts by Willian Nguyen on technology, finance and more"
+            path="/posts"
+        />
         <h1>"Blog Posts"</h1>
         <PostList />
     }
@@ -18,11 +22,11 @@ pub fn PostListPage() -> impl IntoView {
 #[component]
 pub fn PostList() -> impl IntoView {
     let posts = get_content_metadata_list(POSTS);
-    view! { <ContentList items=posts route_prefix="/posts" /> }
+    view! { <PaginatedContentList items=posts base_path="/posts" /> }
 }
 
 #[component]
 pub fn SinglePostPage() -> impl IntoView {
     let params = use_params_map();
-    render_content_page(POSTS, &params)
+    render_content_page(ContentKind::Post, &params)
 }
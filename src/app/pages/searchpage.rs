@@ -0,0 +1,62 @@
+use crate::app::components::{FastA, SearchBox, SeoMeta};
+use crate::shared::content::ContentKind;
+use crate::shared::search::search;
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::hooks::use_query_map;
+
+// Results shown for a query
+const SEARCH_LIMIT: usize = 20;
+
+// NOTE: Searching runs against the index compiled into the bundle, no request is made.
+// The same index is also served by `/api/search?q=` for other consumers.
+#[component]
+pub fn SearchPage() -> impl IntoView {
+    let query = use_query_map();
+    let q = Memo::new(move |_| query.with(|query| query.get("q").unwrap_or_default()));
+
+    view! {
+        <Title text="Willian's blog - Search" />
+        <SeoMeta
+            title="Willian's blog - Search"
+            description="Search Willian Nguyen's posts and poems"
+            path="/search"
+        />
+        <h1>"Search"</h1>
+        <SearchBox value=q />
+        {move || {
+            let q = q.get();
+            if q.trim().is_empty() {
+                return view! { <p>"Type a word to search posts and poems."</p> }.into_any();
+            }
+            let hits = search(&q, SEARCH_LIMIT);
+            if hits.is_empty() {
+                return view! { <p>{format!("No results for \"{q}\".")}</p> }.into_any();
+            }
+            view! {
+                <ul class="search-results">
+                    {hits
+                        .into_iter()
+                        .map(|hit| {
+                            let section = match hit.entry.kind {
+                                ContentKind::Post => "Blog post",
+                                ContentKind::Poem => "Poem",
+                            };
+                            view! {
+                                <li>
+                                    <FastA href=hit.entry.path() class="contrast">
+                                        <strong>{hit.entry.title}</strong>
+                                    </FastA>
+                                    <br />
+                                    <small>{format!("{section} - {}", hit.entry.display_date())}</small>
+                                    <p inner_html=hit.snippet_html></p>
+                                </li>
+                            }
+                        })
+                        .collect::<Vec<_>>()}
+                </ul>
+            }
+            .into_any()
+        }}
+    }
+}
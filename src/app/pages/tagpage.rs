@@ -0,0 +1,75 @@
+use crate::app::components::{ContentList, FastA, SeoMeta};
+use crate::shared::content::{ContentKind, entries_with_tag, tag_counts};
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::hooks::use_params_map;
+
+#[component]
+pub fn TagIndexPage() -> impl IntoView {
+    view! {
+        <Title text="Willian's blog tags" />
+        <SeoMeta
+            title="Willian's blog tags"
+            description="Browse Willian Nguyen's posts and poems by topic"
+            path="/tags"
+        />
+        <h1>"Tags"</h1>
+        <ul class="tag-chips tag-index">
+            {tag_counts()
+                .into_iter()
+                .map(|(tag, count)| {
+                    view! {
+                        <li>
+                            <FastA href=format!("/tags/{tag}") class="tag-chip">
+                                {format!("#{tag}")}
+                                <small>{format!(" ({count})")}</small>
+                            </FastA>
+                        </li>
+                    }
+                })
+                .collect::<Vec<_>>()}
+        </ul>
+    }
+}
+
+#[component]
+pub fn TagPage() -> impl IntoView {
+    let params = use_params_map();
+
+    // The router keeps this view when only the tag changes (eg: a tag chip of the list)
+    move || {
+        let tag = params.with(|p| p.get("tag").unwrap_or_default());
+        let posts = entries_with_tag(ContentKind::Post, &tag);
+        let poems = entries_with_tag(ContentKind::Poem, &tag);
+
+        if posts.is_empty() && poems.is_empty() {
+            return view! { <p>"Not found."</p> }.into_any();
+        }
+
+        view! {
+            <Title text=format!("Willian's blog - #{tag}") />
+            <SeoMeta
+                title=format!("Willian's blog - #{tag}")
+                description=format!("Posts and poems by Willian Nguyen tagged #{tag}")
+                path=format!("/tags/{tag}")
+            />
+            <h1>{format!("#{tag}")}</h1>
+            {(!posts.is_empty())
+                .then(|| {
+                    view! {
+                        <h2>"Blog Posts"</h2>
+                        <ContentList items=posts />
+                    }
+                })}
+            {(!poems.is_empty())
+                .then(|| {
+                    view! {
+                        <h2>"Poem Posts"</h2>
+                        <ContentList items=poems />
+                    }
+                })}
+            <FastA href="/tags">"All tags"</FastA>
+        }
+        .into_any()
+    }
+}
@@ -11,10 +11,16 @@ pub mod services;
 use crate::app::shell;
 use crate::server::{
     db::{config, pool, state::AppState},
-    middleware::global_layer::{cors_layer, security_headers},
+    middleware::{
+        global_layer::{cors_layer, security_headers},
+        redirect::id_redirect,
+    },
     models::status::StatusBadge,
-    routes::{status::status_routes, subscriber::subscriber_routes},
-    services::status::StatusService,
+    routes::{
+        feed::feed_routes, search::search_routes, sitemap::sitemap_routes, status::status_routes,
+        style::style_routes, subscriber::subscriber_routes,
+    },
+    services::{sitemap::SitemapService, status::StatusService},
 };
 use axum::{Router, middleware::from_fn};
 use leptos::prelude::*;
@@ -58,6 +64,7 @@ pub async fn run() {
 
     // Generate the list of routes in your Leptos App
     let routes = generate_route_list(crate::app::App);
+    let sitemap_paths = SitemapService::static_paths(&routes);
 
     // Build the Axum router with Leptos integration and subscriber API
     let app = Router::new()
@@ -71,10 +78,15 @@ pub async fn run() {
             let leptos_options = leptos_options.clone();
             move || shell(leptos_options.clone())
         })
+        .layer(from_fn(id_redirect))
         .fallback(leptos_axum::file_and_error_handler(shell))
         .with_state(leptos_options.clone())
         .merge(subscriber_routes().with_state(app_state))
-        .merge(status_routes(status));
+        .merge(status_routes(status))
+        .merge(feed_routes())
+        .merge(search_routes())
+        .merge(sitemap_routes(sitemap_paths))
+        .merge(style_routes());
 
     // Start the server
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
@@ -0,0 +1,45 @@
+use crate::server::models::feed::{FeedChannel, POEMS_FEED, POSTS_FEED, SITE_FEED};
+use crate::server::services::feed::FeedService;
+use axum::{Json, http::header::CONTENT_TYPE, response::IntoResponse};
+
+const RSS_CONTENT_TYPE: &str = "application/rss+xml; charset=utf-8";
+const ATOM_CONTENT_TYPE: &str = "application/atom+xml; charset=utf-8";
+const JSON_FEED_CONTENT_TYPE: &str = "application/feed+json; charset=utf-8";
+
+/// RSS 2.0 feed of posts and poems
+pub async fn rss_feed() -> impl IntoResponse {
+    rss_response(&SITE_FEED)
+}
+
+/// Atom 1.0 feed of posts and poems
+pub async fn atom_feed() -> impl IntoResponse {
+    (
+        [(CONTENT_TYPE, ATOM_CONTENT_TYPE)],
+        FeedService::atom(&SITE_FEED, "/atom.xml"),
+    )
+}
+
+/// JSON Feed 1.1 of posts and poems
+pub async fn json_feed() -> impl IntoResponse {
+    (
+        [(CONTENT_TYPE, JSON_FEED_CONTENT_TYPE)],
+        Json(FeedService::json(&SITE_FEED, "/feed.json")),
+    )
+}
+
+/// RSS 2.0 feed of blog posts only
+pub async fn posts_feed() -> impl IntoResponse {
+    rss_response(&POSTS_FEED)
+}
+
+/// RSS 2.0 feed of poems only
+pub async fn poems_feed() -> impl IntoResponse {
+    rss_response(&POEMS_FEED)
+}
+
+fn rss_response(channel: &FeedChannel) -> impl IntoResponse {
+    (
+        [(CONTENT_TYPE, RSS_CONTENT_TYPE)],
+        FeedService::rss(channel),
+    )
+}
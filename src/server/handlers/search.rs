@@ -0,0 +1,20 @@
+use crate::server::services::search::SearchService;
+use crate::shared::dto::SearchResult;
+use axum::{Json, extract::Query};
+use serde::Deserialize;
+
+/// Query string of /api/search eg: ?q=rust&limit=5
+#[derive(Deserialize)]
+pub struct SearchRequest {
+    #[serde(default)]
+    pub q: String,
+    pub limit: Option<usize>,
+}
+
+/// Handler for full-text search over posts and poems
+pub async fn search_handler(Query(request): Query<SearchRequest>) -> Json<Vec<SearchResult>> {
+    Json(SearchService::search(
+        &request.q,
+        request.limit.unwrap_or(20),
+    ))
+}
@@ -0,0 +1,21 @@
+use crate::server::services::sitemap::{SitemapPaths, SitemapService};
+use axum::{extract::State, http::header::CONTENT_TYPE, response::IntoResponse};
+
+const SITEMAP_CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+const ROBOTS_CONTENT_TYPE: &str = "text/plain; charset=utf-8";
+
+/// sitemap.xml of every page and content entry
+pub async fn sitemap(State(paths): State<SitemapPaths>) -> impl IntoResponse {
+    (
+        [(CONTENT_TYPE, SITEMAP_CONTENT_TYPE)],
+        SitemapService::sitemap(&paths),
+    )
+}
+
+/// robots.txt pointing crawlers at the sitemap
+pub async fn robots_txt() -> impl IntoResponse {
+    (
+        [(CONTENT_TYPE, ROBOTS_CONTENT_TYPE)],
+        SitemapService::robots(),
+    )
+}
@@ -0,0 +1,11 @@
+use axum::{http::header::CONTENT_TYPE, response::IntoResponse};
+
+const CSS_CONTENT_TYPE: &str = "text/css; charset=utf-8";
+
+// Generated by build.rs from the configured light and dark syntect themes
+const SYNTAX_CSS: &str = include_str!(concat!(env!("OUT_DIR"), "/syntax.css"));
+
+/// Code block colours for the light and dark themes
+pub async fn syntax_css() -> impl IntoResponse {
+    ([(CONTENT_TYPE, CSS_CONTENT_TYPE)], SYNTAX_CSS)
+}
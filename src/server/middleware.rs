@@ -3,4 +3,5 @@ pub mod csrf;
 pub mod forwarded_for_key_extractor;
 pub mod global_layer;
 pub mod governor;
+pub mod redirect;
 pub mod throttle;
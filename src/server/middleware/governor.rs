@@ -28,3 +28,15 @@ pub fn status_badge_governor()
         .unwrap();
     GovernorLayer::new(Box::new(config))
 }
+
+/// Returns a GovernorLayer for search API (e.g., 5 req/sec, burst 20 for search-as-you-type)
+pub fn search_governor()
+-> GovernorLayer<ForwardedForKeyExtractor, NoOpMiddleware<QuantaInstant>, Body> {
+    let config = GovernorConfigBuilder::default()
+        .per_second(5)
+        .burst_size(20)
+        .key_extractor(ForwardedForKeyExtractor)
+        .finish()
+        .unwrap();
+    GovernorLayer::new(Box::new(config))
+}
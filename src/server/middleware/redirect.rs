@@ -0,0 +1,34 @@
+use crate::shared::content::{ContentEntry, ContentKind};
+use axum::{
+    extract::Request,
+    http::{StatusCode, header::LOCATION},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+/// Permanently redirect id URLs (e.g., /posts/0004) to the slug URL of the entry
+/// so links shared before slugs were introduced keep working
+pub async fn id_redirect(request: Request, next: Next) -> Response {
+    let Some(entry) = entry_by_id_path(request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    let location = match request.uri().query() {
+        Some(query) => format!("{}?{query}", entry.path()),
+        None => entry.path(),
+    };
+    (StatusCode::MOVED_PERMANENTLY, [(LOCATION, location)]).into_response()
+}
+
+// Entry addressed by `/<section>/<id>`, slugs are never only digits
+fn entry_by_id_path(path: &str) -> Option<&'static ContentEntry> {
+    [ContentKind::Post, ContentKind::Poem]
+        .iter()
+        .find_map(|kind| {
+            let id = path
+                .strip_prefix(kind.route_prefix())?
+                .strip_prefix('/')?
+                .trim_end_matches('/');
+            kind.entry_by_id(id)
+        })
+}
@@ -0,0 +1,68 @@
+use crate::shared::content::ContentKind;
+use serde::Serialize;
+
+/// A syndication feed: its title and the sections it covers
+#[derive(Debug, Clone, Copy)]
+pub struct FeedChannel {
+    pub title: &'static str,
+    pub description: &'static str,
+    pub kinds: &'static [ContentKind],
+}
+
+/// Everything on the site - /rss.xml, /atom.xml and /feed.json
+pub const SITE_FEED: FeedChannel = FeedChannel {
+    title: "Willian Nguyen",
+    description: "Blog posts and poems by Willian Nguyen",
+    kinds: &[ContentKind::Post, ContentKind::Poem],
+};
+
+/// Blog posts only - /posts/feed.xml
+pub const POSTS_FEED: FeedChannel = FeedChannel {
+    title: "Willian Nguyen - Blog Posts",
+    description: "Blog posts by Willian Nguyen",
+    kinds: &[ContentKind::Post],
+};
+
+/// Poems only - /poems/feed.xml
+pub const POEMS_FEED: FeedChannel = FeedChannel {
+    title: "Willian Nguyen - Poems",
+    description: "Poems by Willian Nguyen",
+    kinds: &[ContentKind::Poem],
+};
+
+/// JSON Feed 1.1 document - https://www.jsonfeed.org/version/1.1/
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonFeed {
+    pub version: &'static str,
+    pub title: &'static str,
+    pub home_page_url: &'static str,
+    pub feed_url: String,
+    pub description: &'static str,
+    pub language: &'static str,
+    pub authors: Vec<JsonFeedAuthor>,
+    pub items: Vec<JsonFeedItem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonFeedAuthor {
+    pub name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonFeedItem {
+    pub id: String,
+    pub url: String,
+    pub title: &'static str,
+    pub content_html: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    pub date_published: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<JsonFeedAuthor>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub tags: &'static [&'static str],
+}
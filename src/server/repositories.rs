@@ -1,2 +1,3 @@
+pub mod content;
 pub mod status;
 pub mod subscriber;
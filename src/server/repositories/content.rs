@@ -0,0 +1,14 @@
+use crate::shared::content::{ContentEntry, ContentKind};
+
+impl ContentEntry {
+    // Listed entries of the given sections, newest first
+    pub fn list(kinds: &[ContentKind]) -> Vec<&'static ContentEntry> {
+        let mut entries: Vec<_> = kinds
+            .iter()
+            .flat_map(|kind| kind.entries())
+            .filter(|entry| entry.is_listed())
+            .collect();
+        entries.sort_by(|a, b| b.date.cmp(&a.date).then(b.id.cmp(&a.id)));
+        entries
+    }
+}
@@ -1,2 +1,6 @@
+pub mod feed;
+pub mod search;
+pub mod sitemap;
 pub mod status;
+pub mod style;
 pub mod subscriber;
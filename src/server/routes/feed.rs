@@ -0,0 +1,13 @@
+use crate::server::handlers::feed::{atom_feed, json_feed, poems_feed, posts_feed, rss_feed};
+use crate::server::middleware::cache::api_cache_layer;
+use axum::{Router, routing::get};
+
+pub fn feed_routes() -> Router {
+    Router::new()
+        .route("/rss.xml", get(rss_feed))
+        .route("/atom.xml", get(atom_feed))
+        .route("/feed.json", get(json_feed))
+        .route("/posts/feed.xml", get(posts_feed))
+        .route("/poems/feed.xml", get(poems_feed))
+        .layer(api_cache_layer())
+}
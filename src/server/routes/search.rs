@@ -0,0 +1,10 @@
+use crate::server::handlers::search::search_handler;
+use crate::server::middleware::{cache::api_cache_layer, governor::search_governor};
+use axum::{Router, routing::get};
+
+pub fn search_routes() -> Router {
+    Router::new()
+        .route("/api/search", get(search_handler))
+        .layer(api_cache_layer())
+        .layer(search_governor())
+}
@@ -0,0 +1,12 @@
+use crate::server::handlers::sitemap::{robots_txt, sitemap};
+use crate::server::middleware::cache::api_cache_layer;
+use crate::server::services::sitemap::SitemapPaths;
+use axum::{Router, routing::get};
+
+pub fn sitemap_routes(paths: SitemapPaths) -> Router {
+    Router::new()
+        .route("/sitemap.xml", get(sitemap))
+        .route("/robots.txt", get(robots_txt))
+        .layer(api_cache_layer())
+        .with_state(paths)
+}
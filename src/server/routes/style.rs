@@ -0,0 +1,10 @@
+use crate::server::handlers::style::syntax_css;
+use crate::server::middleware::cache::static_cache_layer;
+use axum::{Router, routing::get};
+
+// Linked with a version query, so it can be cached like the other static assets
+pub fn style_routes() -> Router {
+    Router::new()
+        .route("/style/syntax.css", get(syntax_css))
+        .layer(static_cache_layer())
+}
@@ -1,2 +1,5 @@
+pub mod feed;
+pub mod search;
+pub mod sitemap;
 pub mod status;
 pub mod subscriber;
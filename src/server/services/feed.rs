@@ -0,0 +1,174 @@
+use crate::server::models::feed::{FeedChannel, JsonFeed, JsonFeedAuthor, JsonFeedItem};
+use crate::shared::content::ContentEntry;
+use crate::shared::site::{SITE_AUTHOR, SITE_URL, absolute_media_url, absolute_url};
+use atom_syndication as atom;
+use uuid::{Uuid, uuid};
+
+// Namespace for feed GUIDs - never change it, readers use GUIDs to detect new items
+const FEED_NAMESPACE: Uuid = uuid!("3b0ad2c4-5b8e-4f65-9a8e-2f7d1c6e4a90");
+
+pub struct FeedService;
+
+impl FeedService {
+    // RSS 2.0 document with the full HTML of every entry in content:encoded
+    pub fn rss(channel: &FeedChannel) -> String {
+        let entries = ContentEntry::list(channel.kinds);
+        let items: Vec<rss::Item> = entries
+            .iter()
+            .map(|entry| rss::Item {
+                title: Some(entry.title.to_string()),
+                link: Some(absolute_url(&entry.path())),
+                description: entry.summary.map(str::to_string),
+                author: entry.author.map(str::to_string),
+                categories: entry
+                    .tags
+                    .iter()
+                    .map(|tag| rss::Category {
+                        name: tag.to_string(),
+                        domain: None,
+                    })
+                    .collect(),
+                guid: Some(rss::Guid {
+                    value: entry_guid(entry),
+                    permalink: false,
+                }),
+                pub_date: Some(entry.published_time().to_rfc2822()),
+                content: Some(entry.html.to_string()),
+                ..Default::default()
+            })
+            .collect();
+
+        let channel = rss::Channel {
+            title: channel.title.to_string(),
+            link: SITE_URL.to_string(),
+            description: channel.description.to_string(),
+            language: Some("en".to_string()),
+            last_build_date: entries
+                .first()
+                .map(|entry| entry.published_time().to_rfc2822()),
+            items,
+            ..Default::default()
+        };
+
+        channel.to_string()
+    }
+
+    // Atom 1.0 document with the full HTML of every entry as content
+    pub fn atom(channel: &FeedChannel, self_path: &str) -> String {
+        let entries = ContentEntry::list(channel.kinds);
+        let author = atom::Person {
+            name: SITE_AUTHOR.to_string(),
+            uri: Some(SITE_URL.to_string()),
+            ..Default::default()
+        };
+
+        let atom_entries: Vec<atom::Entry> = entries
+            .iter()
+            .map(|entry| {
+                let date = entry.published_time().fixed_offset();
+                atom::Entry {
+                    title: entry.title.into(),
+                    id: entry_guid(entry),
+                    updated: date,
+                    published: Some(date),
+                    authors: vec![atom::Person {
+                        name: entry.author.unwrap_or(SITE_AUTHOR).to_string(),
+                        ..Default::default()
+                    }],
+                    categories: entry
+                        .tags
+                        .iter()
+                        .map(|tag| atom::Category {
+                            term: tag.to_string(),
+                            ..Default::default()
+                        })
+                        .collect(),
+                    links: vec![atom::Link {
+                        href: absolute_url(&entry.path()),
+                        rel: "alternate".to_string(),
+                        mime_type: Some("text/html".to_string()),
+                        ..Default::default()
+                    }],
+                    summary: entry.summary.map(atom::Text::plain),
+                    content: Some(atom::Content {
+                        value: Some(entry.html.to_string()),
+                        content_type: Some("html".to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        let feed = atom::Feed {
+            title: channel.title.into(),
+            id: absolute_url(self_path),
+            updated: entries
+                .first()
+                .map(|entry| entry.published_time().fixed_offset())
+                .unwrap_or_default(),
+            authors: vec![author],
+            links: vec![
+                atom::Link {
+                    href: absolute_url(self_path),
+                    rel: "self".to_string(),
+                    mime_type: Some("application/atom+xml".to_string()),
+                    ..Default::default()
+                },
+                atom::Link {
+                    href: SITE_URL.to_string(),
+                    rel: "alternate".to_string(),
+                    mime_type: Some("text/html".to_string()),
+                    ..Default::default()
+                },
+            ],
+            subtitle: Some(channel.description.into()),
+            entries: atom_entries,
+            ..Default::default()
+        };
+
+        feed.to_string()
+    }
+
+    // JSON Feed 1.1 document with the full HTML of every entry as content_html
+    pub fn json(channel: &FeedChannel, self_path: &str) -> JsonFeed {
+        let items = ContentEntry::list(channel.kinds)
+            .into_iter()
+            .map(|entry| JsonFeedItem {
+                id: entry_guid(entry),
+                url: absolute_url(&entry.path()),
+                title: entry.title,
+                content_html: entry.html,
+                summary: entry.summary,
+                image: entry.cover_image.map(absolute_media_url),
+                date_published: entry.published_time().to_rfc3339(),
+                authors: entry
+                    .author
+                    .map(|name| vec![JsonFeedAuthor { name, url: None }])
+                    .unwrap_or_default(),
+                tags: entry.tags,
+            })
+            .collect();
+
+        JsonFeed {
+            version: "https://jsonfeed.org/version/1.1",
+            title: channel.title,
+            home_page_url: SITE_URL,
+            feed_url: absolute_url(self_path),
+            description: channel.description,
+            language: "en",
+            authors: vec![JsonFeedAuthor {
+                name: SITE_AUTHOR,
+                url: Some(SITE_URL),
+            }],
+            items,
+        }
+    }
+}
+
+// Stable GUID derived from section and id - deliberately not from the URL,
+// so a slug or domain change does not make readers show every entry as new
+pub fn entry_guid(entry: &ContentEntry) -> String {
+    let key = format!("{}/{}", entry.kind.route_prefix(), entry.id);
+    format!("urn:uuid:{}", Uuid::new_v5(&FEED_NAMESPACE, key.as_bytes()))
+}
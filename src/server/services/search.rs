@@ -0,0 +1,32 @@
+use crate::shared::content::ContentKind;
+use crate::shared::dto::SearchResult;
+use crate::shared::search::search;
+use crate::shared::site::absolute_url;
+
+// Longer queries are cut, they only make the lookup slower
+const MAX_QUERY_CHARS: usize = 200;
+const MAX_LIMIT: usize = 50;
+
+pub struct SearchService;
+
+impl SearchService {
+    pub fn search(query: &str, limit: usize) -> Vec<SearchResult> {
+        let query: String = query.chars().take(MAX_QUERY_CHARS).collect();
+        search(&query, limit.min(MAX_LIMIT))
+            .into_iter()
+            .map(|hit| SearchResult {
+                kind: match hit.entry.kind {
+                    ContentKind::Post => "post",
+                    ContentKind::Poem => "poem",
+                }
+                .to_string(),
+                id: hit.entry.id,
+                title: hit.entry.title.to_string(),
+                url: absolute_url(&hit.entry.path()),
+                date: hit.entry.date,
+                score: hit.score,
+                snippet_html: hit.snippet_html,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,102 @@
+use crate::shared::content::{ContentKind, all_entries, entries_with_tag, tag_counts};
+use crate::shared::site::absolute_url;
+use chrono::NaiveDate;
+use leptos_axum::AxumRouteListing;
+use std::env;
+use std::fmt::Write;
+use std::sync::Arc;
+
+/// Parameterless paths of the Leptos app, collected once at startup
+pub type SitemapPaths = Arc<Vec<String>>;
+
+// Disallowed by default, overridden by ROBOTS_DISALLOW eg: ROBOTS_DISALLOW=/api/,/tags/
+const DEFAULT_ROBOTS_DISALLOW: &str = "/api/";
+
+pub struct SitemapService;
+
+impl SitemapService {
+    // Static pages of the route list - parameterised routes are expanded from the content tables
+    pub fn static_paths(routes: &[AxumRouteListing]) -> SitemapPaths {
+        let mut paths: Vec<String> = routes
+            .iter()
+            .map(|route| route.path())
+            .filter(|path| !path.contains(['{', ':', '*']))
+            .map(|path| if path.is_empty() { "/" } else { path }.to_string())
+            .collect();
+        paths.sort();
+        paths.dedup();
+        Arc::new(paths)
+    }
+
+    // sitemap.xml of the static pages, every post and poem, and every tag page
+    pub fn sitemap(static_paths: &[String]) -> String {
+        let mut urls: Vec<(String, Option<NaiveDate>)> = static_paths
+            .iter()
+            .map(|path| (path.clone(), section_lastmod(path)))
+            .collect();
+
+        urls.extend(all_entries().map(|entry| (entry.path(), Some(entry.date))));
+
+        urls.extend(tag_counts().into_iter().map(|(tag, _)| {
+            let lastmod = [ContentKind::Post, ContentKind::Poem]
+                .iter()
+                .flat_map(|kind| entries_with_tag(*kind, tag))
+                .map(|entry| entry.date)
+                .max();
+            (format!("/tags/{tag}"), lastmod)
+        }));
+
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        xml.push_str("\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+        for (path, lastmod) in urls {
+            let _ = write!(
+                xml,
+                "  <url><loc>{}</loc>",
+                xml_escape(&absolute_url(&path))
+            );
+            if let Some(date) = lastmod {
+                let _ = write!(xml, "<lastmod>{}</lastmod>", date.format("%Y-%m-%d"));
+            }
+            xml.push_str("</url>\n");
+        }
+        xml.push_str("</urlset>\n");
+        xml
+    }
+
+    // robots.txt allowing everything but the ROBOTS_DISALLOW paths, pointing at the sitemap
+    pub fn robots() -> String {
+        let disallow =
+            env::var("ROBOTS_DISALLOW").unwrap_or_else(|_| DEFAULT_ROBOTS_DISALLOW.to_string());
+
+        let mut robots = String::from("User-agent: *\n");
+        for path in disallow.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let _ = writeln!(robots, "Disallow: {path}");
+        }
+        robots.push_str("Allow: /\n\n");
+        let _ = writeln!(robots, "Sitemap: {}", absolute_url("/sitemap.xml"));
+        robots
+    }
+}
+
+// Listing pages change whenever an entry of their section is published
+fn section_lastmod(path: &str) -> Option<NaiveDate> {
+    let kind = match path {
+        "/" => None,
+        "/posts" => Some(ContentKind::Post),
+        "/poems" => Some(ContentKind::Poem),
+        _ => return None,
+    };
+    all_entries()
+        .filter(|entry| kind.is_none_or(|kind| entry.kind == kind))
+        .map(|entry| entry.date)
+        .max()
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
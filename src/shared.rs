@@ -1 +1,4 @@
+pub mod content;
 pub mod dto;
+pub mod search;
+pub mod site;
@@ -0,0 +1,221 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use std::collections::BTreeMap;
+
+/// Section of the site a content entry belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentKind {
+    Post,
+    Poem,
+}
+
+impl ContentKind {
+    /// Route prefix of the section eg: /posts
+    pub fn route_prefix(&self) -> &'static str {
+        match self {
+            ContentKind::Post => "/posts",
+            ContentKind::Poem => "/poems",
+        }
+    }
+
+    /// Generated content table of the section
+    pub fn entries(&self) -> &'static [ContentEntry] {
+        match self {
+            ContentKind::Post => POSTS,
+            ContentKind::Poem => POEMS,
+        }
+    }
+
+    /// Entry of the section with the given slug
+    pub fn entry_by_slug(&self, slug: &str) -> Option<&'static ContentEntry> {
+        self.entries().iter().find(|entry| entry.slug == slug)
+    }
+
+    /// Entry of the section with the given id
+    /// Ids are matched numerically so zero-padded links (eg: /posts/0004) keep working
+    pub fn entry_by_id(&self, id: &str) -> Option<&'static ContentEntry> {
+        let id = id.parse::<u32>().ok()?;
+        self.entries().iter().find(|entry| entry.id == id)
+    }
+}
+
+/// A heading of a post or poem, with the headings nested under it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TocEntry {
+    pub level: u8,
+    /// Anchor id of the heading eg: #love-at-first-sight
+    pub id: &'static str,
+    pub title: &'static str,
+    pub children: &'static [TocEntry],
+}
+
+/// A post or poem generated from the markdown files in `contents/` by build.rs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContentEntry {
+    pub kind: ContentKind,
+    pub id: u32,
+    pub date: NaiveDate,
+    pub title: &'static str,
+    pub slug: &'static str,
+    pub summary: Option<&'static str>,
+    /// Lowercase, hyphenated tags eg: personal-finance
+    pub tags: &'static [&'static str],
+    pub author: Option<&'static str>,
+    pub cover_image: Option<&'static str>,
+    /// Only built in dev, see build.rs
+    pub draft: bool,
+    /// Unix timestamp before which the entry is not published
+    pub publish_at: Option<i64>,
+    pub html: &'static str,
+    /// Table of contents - h2 to h4 headings, nested
+    pub toc: &'static [TocEntry],
+    /// Words of the prose, syllables for Vietnamese text
+    pub word_count: u32,
+    /// Estimated reading time, at least one minute
+    pub reading_minutes: u32,
+    /// Meta description - front matter summary or the start of the first paragraph
+    pub description: &'static str,
+    /// Ids of related entries of the same section by shared tags and wording, most related first
+    pub related: &'static [u32],
+}
+
+impl ContentEntry {
+    /// Date formatted as DD-MMM-YYYY eg: 05-Sep-2025
+    pub fn display_date(&self) -> String {
+        self.date.format("%d-%b-%Y").to_string()
+    }
+
+    /// Reading time eg: 5 min read
+    pub fn reading_time(&self) -> String {
+        format!("{} min read", self.reading_minutes)
+    }
+
+    /// Publication time of the date - content only carries a date, published at midnight UTC
+    pub fn published_time(&self) -> DateTime<Utc> {
+        self.date.and_hms_opt(0, 0, 0).unwrap().and_utc()
+    }
+
+    /// Scheduled publication time, if any
+    pub fn publish_time(&self) -> Option<DateTime<Utc>> {
+        self.publish_at
+            .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+    }
+
+    /// Whether the scheduled publication time, if any, has been reached
+    pub fn is_published(&self) -> bool {
+        self.publish_time()
+            .is_none_or(|publish_time| publish_time <= Utc::now())
+    }
+
+    /// Whether the entry shows up in lists, feeds, sitemaps and search
+    /// Drafts are only reachable at their URL
+    pub fn is_listed(&self) -> bool {
+        !self.draft && self.is_published()
+    }
+
+    /// Listed entries before and after this one in the section - older first, then newer
+    pub fn neighbours(&self) -> (Option<&'static ContentEntry>, Option<&'static ContentEntry>) {
+        let listed: Vec<&'static ContentEntry> = self
+            .kind
+            .entries()
+            .iter()
+            .filter(|entry| entry.is_listed() || entry.id == self.id)
+            .collect();
+        let Some(index) = listed.iter().position(|entry| entry.id == self.id) else {
+            return (None, None);
+        };
+        // Tables are sorted newest first
+        let older = listed.get(index + 1).copied();
+        let newer = index.checked_sub(1).map(|newer| listed[newer]);
+        (older, newer)
+    }
+
+    /// Listed related entries
+    pub fn related_entries(&self) -> Vec<&'static ContentEntry> {
+        self.related
+            .iter()
+            .filter_map(|id| self.kind.entries().iter().find(|entry| entry.id == *id))
+            .filter(|entry| entry.is_listed())
+            .collect()
+    }
+
+    /// Canonical site-relative URL of the entry eg: /posts/deploy-my-own-blog-finally
+    pub fn path(&self) -> String {
+        format!("{}/{}", self.kind.route_prefix(), self.slug)
+    }
+}
+
+/// All listed posts and poems, posts first
+pub fn all_entries() -> impl Iterator<Item = &'static ContentEntry> {
+    POSTS
+        .iter()
+        .chain(POEMS.iter())
+        .filter(|entry| entry.is_listed())
+}
+
+/// Every tag in use with the number of entries carrying it, sorted by tag
+pub fn tag_counts() -> Vec<(&'static str, usize)> {
+    let mut counts = BTreeMap::new();
+    for tag in all_entries().flat_map(|entry| entry.tags) {
+        *counts.entry(*tag).or_insert(0) += 1;
+    }
+    counts.into_iter().collect()
+}
+
+/// Listed entries of one year of the archive, by month
+#[derive(Debug, Clone)]
+pub struct ArchiveYear {
+    pub year: i32,
+    pub months: Vec<ArchiveMonth>,
+}
+
+/// Listed entries of one month of the archive
+#[derive(Debug, Clone)]
+pub struct ArchiveMonth {
+    /// 1 to 12
+    pub month: u32,
+    pub entries: Vec<&'static ContentEntry>,
+}
+
+impl ArchiveYear {
+    pub fn count(&self) -> usize {
+        self.months.iter().map(|month| month.entries.len()).sum()
+    }
+}
+
+/// Listed posts and poems grouped by year then month, newest first
+pub fn archive() -> Vec<ArchiveYear> {
+    let mut entries: Vec<_> = all_entries().collect();
+    entries.sort_by(|a, b| b.date.cmp(&a.date).then(b.id.cmp(&a.id)));
+
+    let mut years: Vec<ArchiveYear> = Vec::new();
+    for entry in entries {
+        let (year, month) = (entry.date.year(), entry.date.month());
+        if years.last().is_none_or(|last| last.year != year) {
+            years.push(ArchiveYear {
+                year,
+                months: Vec::new(),
+            });
+        }
+        let months = &mut years.last_mut().unwrap().months;
+        if months.last().is_none_or(|last| last.month != month) {
+            months.push(ArchiveMonth {
+                month,
+                entries: Vec::new(),
+            });
+        }
+        months.last_mut().unwrap().entries.push(entry);
+    }
+    years
+}
+
+/// Listed entries of a section carrying the given tag
+pub fn entries_with_tag(kind: ContentKind, tag: &str) -> Vec<&'static ContentEntry> {
+    kind.entries()
+        .iter()
+        .filter(|entry| entry.is_listed() && entry.tags.contains(&tag))
+        .collect()
+}
+
+// Static content tables generated by build.rs - sorted by id, newest first
+include!(concat!(env!("OUT_DIR"), "/posts_data.rs"));
+include!(concat!(env!("OUT_DIR"), "/poems_data.rs"));
@@ -5,3 +5,15 @@ pub struct SubscribeResponse {
     pub status: String,
     pub message: String,
 }
+
+/// A search result returned by /api/search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub kind: String,
+    pub id: u32,
+    pub title: String,
+    pub url: String,
+    pub date: chrono::NaiveDate,
+    pub score: f32,
+    pub snippet_html: String,
+}
@@ -0,0 +1,167 @@
+mod tokenize;
+
+use tokenize::is_combining_mark;
+pub use tokenize::{fold, tokenize};
+
+use crate::shared::content::{ContentEntry, ContentKind};
+use std::collections::HashMap;
+
+// Words shown around the first match in a snippet
+const SNIPPET_WORDS: usize = 30;
+const SNIPPET_LEAD: usize = 8;
+
+/// Plain text of a post or poem, indexed by build.rs
+#[derive(Debug, Clone, Copy)]
+pub struct SearchDoc {
+    pub kind: ContentKind,
+    pub id: u32,
+    pub text: &'static str,
+}
+
+/// A ranked search result with an HTML snippet, matches wrapped in `<mark>`
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub entry: &'static ContentEntry,
+    pub score: f32,
+    pub snippet_html: String,
+}
+
+/// Entries matching every word of the query, best first
+/// Accents are ignored and the last word also matches as a prefix, so results update while typing
+pub fn search(query: &str, limit: usize) -> Vec<SearchHit> {
+    let tokens = tokenize(query);
+    let Some((last, rest)) = tokens.split_last() else {
+        return Vec::new();
+    };
+
+    // Sum of tf-idf per doc, counting the query words each doc matched
+    let total_docs = SEARCH_DOCS.len() as f32;
+    let mut scores: HashMap<u16, (f32, usize)> = HashMap::new();
+    let lookups = rest
+        .iter()
+        .map(|token| postings(token, false))
+        .chain([postings(last, true)]);
+    for matches in lookups {
+        let idf = ((total_docs + 1.0) / (matches.len() as f32 + 1.0)).ln() + 1.0;
+        for (doc, weight) in matches {
+            let score = scores.entry(doc).or_default();
+            score.0 += f32::from(weight) * idf;
+            score.1 += 1;
+        }
+    }
+
+    let mut hits: Vec<SearchHit> = scores
+        .into_iter()
+        .filter(|(_, (_, matched))| *matched == tokens.len())
+        .filter_map(|(doc, (score, _))| {
+            let doc = &SEARCH_DOCS[usize::from(doc)];
+            let entry = doc
+                .kind
+                .entries()
+                .iter()
+                .find(|entry| entry.id == doc.id)
+                .filter(|entry| entry.is_listed())?;
+            Some(SearchHit {
+                entry,
+                score,
+                snippet_html: snippet_html(doc.text, &tokens),
+            })
+        })
+        .collect();
+    hits.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(b.entry.date.cmp(&a.entry.date))
+    });
+    hits.truncate(limit);
+    hits
+}
+
+// Weight per doc of a term, or of every term it prefixes
+fn postings(token: &str, prefix: bool) -> HashMap<u16, u16> {
+    let start = SEARCH_TERMS.partition_point(|(term, _)| *term < token);
+    let mut matches: HashMap<u16, u16> = HashMap::new();
+    for (term, term_postings) in &SEARCH_TERMS[start..] {
+        let matched = if prefix {
+            term.starts_with(token)
+        } else {
+            *term == token
+        };
+        if !matched {
+            break;
+        }
+        for (doc, weight) in term_postings.iter() {
+            let total = matches.entry(*doc).or_default();
+            *total = total.saturating_add(*weight);
+        }
+    }
+    matches
+}
+
+// A window of the text around the first matching word, with every match highlighted
+fn snippet_html(text: &str, tokens: &[String]) -> String {
+    let words = word_spans(text);
+    let is_match = |&(start, end): &(usize, usize)| {
+        let word = fold(&text[start..end]);
+        tokens.iter().enumerate().any(|(index, token)| {
+            word == *token || (index == tokens.len() - 1 && word.starts_with(token.as_str()))
+        })
+    };
+
+    let first = words.iter().position(is_match).unwrap_or(0);
+    let from = first.saturating_sub(SNIPPET_LEAD);
+    let to = words.len().min(from + SNIPPET_WORDS);
+    let Some(window) = words.get(from..to).filter(|window| !window.is_empty()) else {
+        return String::new();
+    };
+
+    let mut html = String::new();
+    if from > 0 {
+        html.push('…');
+    }
+    let mut cursor = window[0].0;
+    for span in window {
+        html.push_str(&escape_html(&text[cursor..span.0]));
+        let word = escape_html(&text[span.0..span.1]);
+        if is_match(span) {
+            html.push_str(&format!("<mark>{word}</mark>"));
+        } else {
+            html.push_str(&word);
+        }
+        cursor = span.1;
+    }
+    if to < words.len() {
+        html.push('…');
+    }
+    html
+}
+
+// Byte ranges of the words of the text
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (index, c) in text.char_indices() {
+        let in_word = c.is_alphanumeric() || is_combining_mark(c);
+        match (in_word, start) {
+            (true, None) => start = Some(index),
+            (false, Some(from)) => {
+                spans.push((from, index));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(from) = start {
+        spans.push((from, text.len()));
+    }
+    spans
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+// Inverted index generated by build.rs - terms are folded with `tokenize` and sorted
+include!(concat!(env!("OUT_DIR"), "/search_index.rs"));
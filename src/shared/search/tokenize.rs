@@ -0,0 +1,50 @@
+// Text normalisation shared by the search index (build.rs) and search queries (app/server)
+// Kept free of crate dependencies so build.rs can include it with #[path]
+
+// Base letter and its accented lowercase forms - covers Vietnamese and common Latin accents
+const FOLDS: &[(char, &str)] = &[
+    ('a', "àáảãạăằắẳẵặâầấẩẫậäåā"),
+    ('c', "ç"),
+    ('d', "đ"),
+    ('e', "èéẻẽẹêềếểễệëē"),
+    ('i', "ìíỉĩịîïī"),
+    ('n', "ñ"),
+    ('o', "òóỏõọôồốổỗộơờớởỡợöøō"),
+    ('u', "ùúủũụưừứửữựûüū"),
+    ('y', "ỳýỷỹỵÿ"),
+];
+
+// Words shorter than this are not indexed
+pub const MIN_TOKEN_LEN: usize = 2;
+
+/// Lowercase and strip diacritics eg: `Đừng` -> `dung`
+pub fn fold(text: &str) -> String {
+    text.chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !is_combining_mark(*c))
+        .map(fold_char)
+        .collect()
+}
+
+/// Folded words of the text, in order, including repeats
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && !is_combining_mark(c))
+        .map(fold)
+        .filter(|token| token.chars().count() >= MIN_TOKEN_LEN)
+        .collect()
+}
+
+fn fold_char(c: char) -> char {
+    if c.is_ascii() {
+        return c;
+    }
+    FOLDS
+        .iter()
+        .find(|(_, accented)| accented.contains(c))
+        .map_or(c, |(base, _)| *base)
+}
+
+/// Decomposed accents (NFD input) are dropped rather than splitting words
+pub fn is_combining_mark(c: char) -> bool {
+    ('\u{0300}'..='\u{036f}').contains(&c)
+}
@@ -0,0 +1,38 @@
+// Site-wide constants shared by the app and the server
+// SITE_URL can be overridden at compile time eg: SITE_URL=http://localhost:3000
+
+/// Public origin of the site, without trailing slash
+pub const SITE_URL: &str = match option_env!("SITE_URL") {
+    Some(url) => url,
+    None => "https://williannguyen.com",
+};
+
+pub const SITE_TITLE: &str = "Willian Nguyen";
+pub const SITE_DESCRIPTION: &str = "Willian's personal website";
+pub const SITE_AUTHOR: &str = "Willian Nguyen";
+
+/// Entries per page of the post and poem lists
+pub const LIST_PAGE_SIZE: usize = 10;
+
+/// Profiles of the author elsewhere, for structured data
+pub const AUTHOR_PROFILES: &[&str] = &[
+    "https://github.com/hnlearndev",
+    "https://www.linkedin.com/in/hieunthello/",
+];
+
+/// Absolute URL of a site-relative path eg: /posts/4
+pub fn absolute_url(path: &str) -> String {
+    format!("{SITE_URL}{path}")
+}
+
+/// Absolute URL of an image or link that may be site-relative or already absolute
+pub fn absolute_media_url(src: &str) -> String {
+    if src.starts_with('/') {
+        absolute_url(src)
+    } else {
+        src.to_string()
+    }
+}
+
+// Code block stylesheet version generated by build.rs, served at /style/syntax.css
+include!(concat!(env!("OUT_DIR"), "/syntax_css.rs"));
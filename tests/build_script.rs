@@ -0,0 +1,4 @@
+// Runs the unit tests of the build script modules - `main` is compiled but not called
+#![allow(dead_code)]
+
+include!("../build.rs");